@@ -37,4 +37,74 @@ pub enum ErrorCode {
     RewardsAlreadyClaimed,
     #[msg("Insufficient Funds")]
     InsufficientFunds,
+    #[msg("Proposal is not active")]
+    ProposalNotActive,
+    #[msg("Invalid Recipient")]
+    InvalidRecipient,
+    #[msg("Invalid proposal status transition")]
+    InvalidProposalStatus,
+    #[msg("Dao member account required")]
+    MemberRequired,
+    #[msg("Insufficient Reputation")]
+    InsufficientReputation,
+    #[msg("Invalid Delegation")]
+    InvalidDelegation,
+    #[msg("Already Voted")]
+    AlreadyVoted,
+    #[msg("Proposal must be queued before execution")]
+    ProposalNotQueued,
+    #[msg("Timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Instruction exceeds the account or data limit")]
+    InstructionTooLarge,
+    #[msg("Proposal can no longer be changed once voting has started")]
+    VotingStarted,
+    #[msg("Instruction already executed")]
+    InstructionAlreadyExecuted,
+    #[msg("Instructions must be executed in order")]
+    InvalidInstructionIndex,
+    #[msg("Accounts do not match the proposal instruction")]
+    InvalidInstructionAccounts,
+    #[msg("Vote type does not match the proposal")]
+    InvalidVoteType,
+    #[msg("Invalid proposal choices")]
+    InvalidChoices,
+    #[msg("Invalid reward configuration")]
+    InvalidRewardConfig,
+    #[msg("Nothing to claim yet")]
+    NothingToClaim,
+    #[msg("Proposal has not been finalized")]
+    ProposalNotFinalized,
+    #[msg("Proposal has already been finalized")]
+    ProposalAlreadyFinalized,
+    #[msg("Invalid Amount")]
+    InvalidAmount,
+    #[msg("Lock duration must be between a week and four years")]
+    InvalidLockDuration,
+    #[msg("Tokens are still locked")]
+    LockNotExpired,
+    #[msg("Fee split cannot exceed 100%")]
+    InvalidFeeSplit,
+    #[msg("DAO has been dissolved")]
+    DaoDissolved,
+    #[msg("DAO has not been dissolved")]
+    DaoNotDissolved,
+    #[msg("Proposal still has open votes or pending instructions")]
+    ProposalNotClosable,
+    #[msg("Vote still holds stake or rewards")]
+    VoteNotSettled,
+    #[msg("Discussion period has not ended yet")]
+    DiscussionNotEnded,
+    #[msg("Role already has the maximum number of holders")]
+    RoleLimitReached,
+    #[msg("Role not found")]
+    RoleNotFound,
+    #[msg("Treasurer spending limit exceeded")]
+    SpendingLimitExceeded,
+    #[msg("Multi-choice proposals cannot carry a cost or instructions")]
+    MultiChoicePayload,
+    #[msg("Every instruction record of the proposal must be closed with it")]
+    InstructionRecordsRequired,
+    #[msg("Depositors can still claim the dissolved treasury")]
+    DissolutionClaimsOpen,
 }
@@ -0,0 +1,190 @@
+use crate::state::{ProposalStatus, VoteType};
+use anchor_lang::prelude::*;
+
+// Events emitted on governance state changes so indexers can
+// rebuild dao history from transaction logs
+
+#[event]
+pub struct DaoCreated {
+    pub dao: Pubkey,
+    pub creator: Pubkey,
+    pub dao_mint: Pubkey,
+    pub dao_seed: u64,
+    // position in the daoverse dao registry
+    pub dao_index: u64,
+    pub dao_name: String,
+}
+
+#[event]
+pub struct DaoUpdated {
+    pub dao: Pubkey,
+    // dao creator, or the dao itself through a proposal
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct MemberJoined {
+    pub dao: Pubkey,
+    pub member: Pubkey,
+    pub user: Pubkey,
+    pub joined_at: i64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    // position in the dao proposal registry
+    pub proposal_index: u64,
+    pub status: ProposalStatus,
+    pub proposal_cost: u64,
+    pub recipient: Pubkey,
+    pub voting_start_time: i64,
+    pub voting_end_time: i64,
+    pub content_hash: [u8; 32],
+}
+
+#[event]
+pub struct VoteCast {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub vote_type: VoteType,
+    pub tokens_staked: u64,
+    // the voter's own weight, delegated weight is reported separately
+    pub vote_weight: u64,
+    pub delegated_weight: u64,
+    pub delegated_votes: u64,
+}
+
+#[event]
+pub struct ProposalFinalized {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub status: ProposalStatus,
+    pub quorum_reached: bool,
+    pub winning_vote: Option<VoteType>,
+    pub reward_pool: u64,
+    pub forfeited_stake: u64,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    // stake returned on this claim, after any forfeit
+    pub stake_returned: u64,
+    // vested reward plus any share of forfeits
+    pub rewards: u64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub recipient: Pubkey,
+    pub proposal_cost: u64,
+}
+
+#[event]
+pub struct VoteChanged {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub vote_type: VoteType,
+    pub tokens_staked: u64,
+}
+
+#[event]
+pub struct VoteWithdrawn {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub vote_type: VoteType,
+    pub stake_returned: u64,
+    // delegator records closed along with the vote
+    pub delegated_votes: u64,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    // proposal owner, guardian or council member
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ProposalQueued {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub eta: i64,
+}
+
+#[event]
+pub struct ProposalInstructionExecuted {
+    pub dao: Pubkey,
+    pub proposal: Pubkey,
+    pub index: u16,
+    pub program_id: Pubkey,
+}
+
+#[event]
+pub struct MemberLeft {
+    pub dao: Pubkey,
+    pub member: Pubkey,
+    pub user: Pubkey,
+}
+
+#[event]
+pub struct VotesDelegated {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub dao_scope: Pubkey,
+}
+
+#[event]
+pub struct DelegationRevoked {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub dao_scope: Pubkey,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub dao: Pubkey,
+    // None for sol
+    pub mint: Option<Pubkey>,
+    // token account for mints, wallet for sol
+    pub recipient: Pubkey,
+    pub amount: u64,
+    // the dao itself through a proposal, or a treasurer
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct DaoDissolved {
+    pub dao: Pubkey,
+    pub dissolution_pool: u64,
+    pub dissolution_weight: u64,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct DissolutionClaimed {
+    pub dao: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct DissolutionSwept {
+    pub dao: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub lamports: u64,
+}
@@ -49,6 +49,7 @@ impl<'info> InitializeDaoverse<'info> {
         &mut self,
         bumps: InitializeDaoverseBumps,
         dao_creation_fee: u64,
+        min_creator_tokens: u64,
         admin_name: String,
         daoverse_description: String,
     ) {
@@ -56,10 +57,15 @@ impl<'info> InitializeDaoverse<'info> {
             admin: self.admin.key(),
             daoverse_mint: self.daoverse_mint.key(),
             dao_creation_fee,
+            min_creator_tokens,
             bump: bumps.daoverse,
             daoverse_treasury_balance: 0,
             admin_name,
             daoverse_description,
+            pending_admin: None,
+            fee_beneficiary: self.admin.key(),
+            fee_split_bps: 0,
+            dao_count: 0,
         });
     }
 
@@ -3,3 +3,9 @@ pub use initialize_config::*;
 
 pub mod update_config;
 pub use update_config::*;
+
+pub mod withdraw_config;
+pub use withdraw_config::*;
+
+pub mod transfer_admin;
+pub use transfer_admin::*;
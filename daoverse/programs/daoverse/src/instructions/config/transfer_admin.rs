@@ -0,0 +1,51 @@
+use crate::errors::ErrorCode;
+use crate::state::DaoverseConfig;
+
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    // Only admin can hand over the daoverse
+    #[account(address = daoverse.admin @ ErrorCode::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"daoverse".as_ref()],
+        bump = daoverse.bump,
+    )]
+    pub daoverse: Box<Account<'info, DaoverseConfig>>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    pub fn propose_admin(&mut self, new_admin: Option<Pubkey>) -> Result<()> {
+        self.daoverse.pending_admin = new_admin;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    // the proposed admin has to sign so the daoverse is never handed to a wrong key
+    #[account(
+        constraint = daoverse.pending_admin == Some(new_admin.key()) @ ErrorCode::Unauthorized
+    )]
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"daoverse".as_ref()],
+        bump = daoverse.bump,
+    )]
+    pub daoverse: Box<Account<'info, DaoverseConfig>>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept_admin(&mut self) -> Result<()> {
+        self.daoverse.admin = self.new_admin.key();
+        self.daoverse.pending_admin = None;
+
+        Ok(())
+    }
+}
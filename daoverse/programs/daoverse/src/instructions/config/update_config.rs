@@ -1,5 +1,5 @@
 use crate::errors::ErrorCode;
-use crate::state::DaoverseConfig;
+use crate::state::{DaoverseConfig, BPS_DENOMINATOR};
 
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
@@ -39,14 +39,22 @@ impl<'info> UpdateDaoverse<'info> {
     pub fn update_daoverse(
         &mut self,
         dao_creation_fee: Option<u64>,
+        min_creator_tokens: Option<u64>,
         admin_name: Option<String>,
         daoverse_description: Option<String>,
+        fee_beneficiary: Option<Pubkey>,
+        fee_split_bps: Option<u16>,
     ) -> Result<()> {
         // Update dao creation fee if provided
         if let Some(fee) = dao_creation_fee {
             self.daoverse.dao_creation_fee = fee;
         }
 
+        // Update minimum creator balance if provided
+        if let Some(min_tokens) = min_creator_tokens {
+            self.daoverse.min_creator_tokens = min_tokens;
+        }
+
         // Update admin name if provided
         if let Some(name) = admin_name {
             require!(name.len() <= 32, ErrorCode::StringTooLong);
@@ -59,6 +67,17 @@ impl<'info> UpdateDaoverse<'info> {
             self.daoverse.daoverse_description = description;
         }
 
+        // Update where the fee split is sent if provided
+        if let Some(beneficiary) = fee_beneficiary {
+            self.daoverse.fee_beneficiary = beneficiary;
+        }
+
+        // Update the beneficiary share of creation fees if provided
+        if let Some(split) = fee_split_bps {
+            require!(split as u64 <= BPS_DENOMINATOR, ErrorCode::InvalidFeeSplit);
+            self.daoverse.fee_split_bps = split;
+        }
+
         // Update treasury balance
         self.daoverse.daoverse_treasury_balance = self.daoverse_treasury.amount;
 
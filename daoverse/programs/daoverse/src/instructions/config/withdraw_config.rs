@@ -0,0 +1,75 @@
+use crate::errors::ErrorCode;
+use crate::state::DaoverseConfig;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawDaoverseTreasury<'info> {
+    // Only admin can withdraw
+    #[account(
+        mut,
+        address = daoverse.admin @ ErrorCode::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"daoverse".as_ref()],
+        bump = daoverse.bump,
+        has_one = daoverse_mint @ ErrorCode::Unauthorized
+    )]
+    pub daoverse: Box<Account<'info, DaoverseConfig>>,
+
+    pub daoverse_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = daoverse_mint,
+        associated_token::authority = daoverse,
+    )]
+    pub daoverse_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    //admin ata
+    #[account(
+        mut,
+        associated_token::mint = daoverse_mint,
+        associated_token::authority = admin,
+    )]
+    pub admin_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawDaoverseTreasury<'info> {
+    pub fn withdraw_daoverse_treasury(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            self.daoverse_treasury.amount >= amount,
+            ErrorCode::InsufficientFunds
+        );
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.daoverse_treasury.to_account_info(),
+                    mint: self.daoverse_mint.to_account_info(),
+                    to: self.admin_ata.to_account_info(),
+                    authority: self.daoverse.to_account_info(),
+                },
+                &[&[b"daoverse".as_ref(), &[self.daoverse.bump]]],
+            ),
+            amount,
+            self.daoverse_mint.decimals,
+        )?;
+
+        // Update treasury balance
+        self.daoverse_treasury.reload()?;
+        self.daoverse.daoverse_treasury_balance = self.daoverse_treasury.amount;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+// Accounts for claiming a share of a dissolved dao's treasury
+#[derive(Accounts)]
+pub struct ClaimDissolution<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    // shares follow governance deposits rather than memberships, one claim per deposit.
+    // a member account holds no tokens and the member count says nothing about stake,
+    // the deposit checkpoints are the only holdings we can read at the dissolved slot,
+    // so members who never deposited have no share to claim
+    #[account(
+        mut,
+        seeds = [b"governance", dao.key().as_ref(), owner.key().as_ref()],
+        bump = governance_deposit.bump,
+        constraint = !governance_deposit.dissolution_claimed @ ErrorCode::RewardsAlreadyClaimed,
+    )]
+    pub governance_deposit: Box<Account<'info, GovernanceDepositState>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = dao,
+    )]
+    pub dao_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_dao_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_treasury", dao.key().as_ref()],
+        bump,
+    )]
+    pub sol_treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimDissolution<'info> {
+    // Pay the depositor's pro-rata share of what is left in the treasuries
+    // so slashed deposits and forfeits arriving after dissolution are shared too
+    pub fn claim_dissolution(&mut self, bumps: ClaimDissolutionBumps) -> Result<()> {
+        require!(self.dao.dissolved, ErrorCode::DaoNotDissolved);
+        require!(
+            self.dao
+                .dissolution_claims_open(Clock::get()?.unix_timestamp),
+            ErrorCode::NothingToClaim
+        );
+
+        let weight = self.governance_deposit.balance_at(self.dao.dissolved_slot);
+        require!(weight > 0, ErrorCode::NothingToClaim);
+        let remaining_weight = self.dao.dissolution_weight;
+
+        let share = dissolution_share(self.dao_treasury.amount, 0, weight, remaining_weight)
+            .ok_or(ErrorCode::CalculationError)?;
+        // the sol treasury stays rent exempt until the last claim
+        let sol_share = dissolution_share(
+            self.sol_treasury.lamports(),
+            Rent::get()?.minimum_balance(0),
+            weight,
+            remaining_weight,
+        )
+        .ok_or(ErrorCode::CalculationError)?;
+
+        if share > 0 {
+            self.pay_tokens(share)?;
+        }
+        if sol_share > 0 {
+            let dao_key = self.dao.key();
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.sol_treasury.to_account_info(),
+                        to: self.owner.to_account_info(),
+                    },
+                    &[&[b"sol_treasury", dao_key.as_ref(), &[bumps.sol_treasury]]],
+                ),
+                sol_share,
+            )?;
+        }
+
+        self.governance_deposit.dissolution_claimed = true;
+        self.dao.dissolution_weight = remaining_weight.saturating_sub(weight);
+        self.dao_treasury.reload()?;
+        self.dao.dao_treasury_balance = self.dao_treasury.amount;
+
+        emit!(DissolutionClaimed {
+            dao: self.dao.key(),
+            owner: self.owner.key(),
+            amount: share,
+            lamports: sol_share,
+        });
+
+        Ok(())
+    }
+
+    fn pay_tokens(&self, share: u64) -> Result<()> {
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.dao_treasury.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to: self.owner_dao_ata.to_account_info(),
+                    authority: self.dao.to_account_info(),
+                },
+                &[&[
+                    b"dao",
+                    self.dao.dao_creator.to_bytes().as_ref(),
+                    self.dao.dao_seed.to_le_bytes().as_ref(),
+                    &[self.dao.bump],
+                ]],
+            ),
+            share,
+            self.dao_mint.decimals,
+        )
+    }
+}
@@ -1,4 +1,5 @@
 use crate::errors::ErrorCode;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
@@ -9,7 +10,6 @@ use anchor_spl::{
 
 #[derive(Accounts)]
 #[instruction(dao_seed: u64)]
-
 pub struct CreateDao<'info> {
     //dao creator
     #[account(mut)]
@@ -69,6 +69,24 @@ pub struct CreateDao<'info> {
     )]
     pub creator_daoverse_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    //fee beneficiary ata - only needed when the daoverse splits creation fees
+    #[account(
+        mut,
+        constraint = fee_beneficiary_ata.mint == daoverse_mint.key() @ ErrorCode::InvalidDaoverseMint,
+        constraint = fee_beneficiary_ata.owner == daoverse.fee_beneficiary @ ErrorCode::InvalidRecipient,
+    )]
+    pub fee_beneficiary_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    //next entry of the daoverse dao registry
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + DaoIndex::INIT_SPACE,
+        seeds = [b"dao_index", daoverse.dao_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub dao_index: Box<Account<'info, DaoIndex>>,
+
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -76,20 +94,55 @@ pub struct CreateDao<'info> {
 
 impl<'info> CreateDao<'info> {
     //validate dao creator
-    //to validate, creator_daoverse_ata amount is greater than 1000
+    //to validate, creator_daoverse_ata holds at least the daoverse min_creator_tokens
     pub fn validate_creator(&self) -> Result<()> {
         if self.creator_daoverse_ata.mint != self.daoverse_mint.key() {
             return err!(ErrorCode::InvalidDaoverseMint);
         }
-        if self.creator_daoverse_ata.amount < 1000 {
+        let min_creator_amount = to_base_units(
+            self.daoverse.min_creator_tokens,
+            self.daoverse_mint.decimals,
+        )
+        .ok_or(ErrorCode::CalculationError)?;
+        if self.creator_daoverse_ata.amount < min_creator_amount {
             return err!(ErrorCode::InsufficientDaoverseTokens);
         }
         Ok(())
     }
 
     //pay daoverse fee
-    //daoverse fee is 500
+    //daoverse fee is the daoverse dao_creation_fee
+    //part of it goes to the fee beneficiary when the daoverse splits fees
     pub fn pay_daoverse_fee(&mut self) -> Result<()> {
+        let fee = to_base_units(self.daoverse.dao_creation_fee, self.daoverse_mint.decimals)
+            .ok_or(ErrorCode::CalculationError)?;
+        let beneficiary_share = self
+            .daoverse
+            .beneficiary_share(fee)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        if beneficiary_share > 0 {
+            let beneficiary_ata = self
+                .fee_beneficiary_ata
+                .as_ref()
+                .ok_or(ErrorCode::InvalidRecipient)?;
+
+            transfer_checked(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.creator_daoverse_ata.to_account_info(),
+                        mint: self.daoverse_mint.to_account_info(),
+                        to: beneficiary_ata.to_account_info(),
+                        authority: self.creator.to_account_info(),
+                    },
+                ),
+                beneficiary_share,
+                self.daoverse_mint.decimals,
+            )?;
+        }
+        let fee = fee - beneficiary_share;
+
         let cpi_program = self.token_program.to_account_info();
 
         let cpi_accounts = TransferChecked {
@@ -101,20 +154,18 @@ impl<'info> CreateDao<'info> {
 
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
 
-        transfer_checked(cpi_ctx, 500, self.daoverse_mint.decimals)?;
+        transfer_checked(cpi_ctx, fee, self.daoverse_mint.decimals)?;
 
-        // Update daoverse treasury balance
-        self.daoverse.daoverse_treasury_balance = self
-            .daoverse
-            .daoverse_treasury_balance
-            .checked_add(500)
-            .ok_or(ErrorCode::Overflow)?;
+        // Update daoverse treasury balance from the treasury ata
+        self.daoverse_treasury.reload()?;
+        self.daoverse.daoverse_treasury_balance = self.daoverse_treasury.amount;
 
         Ok(())
     }
 
     //create dao
     //initialize dao
+    #[allow(clippy::too_many_arguments)]
     pub fn create_dao(
         &mut self,
         bumps: CreateDaoBumps,
@@ -125,7 +176,12 @@ impl<'info> CreateDao<'info> {
         voting_model: dao_config::VotingModel,
         reward_model: dao_config::RewardModel,
         voting_threshold: dao_config::VotingThreshold,
-    ) {
+        eligibility_threshold: dao_config::EligibilityThreshold,
+        reward_config: dao_config::RewardConfig,
+    ) -> Result<()> {
+        require!(reward_config.is_valid(), ErrorCode::InvalidRewardConfig);
+        require!(voting_threshold.is_valid(), ErrorCode::InvalidThreshold);
+
         self.dao.set_inner(DaoConfig {
             dao_seed,
             dao_creator: self.creator.key(),
@@ -141,7 +197,43 @@ impl<'info> CreateDao<'info> {
             voting_model,
             reward_model,
             voting_threshold,
+            eligibility_threshold,
+            guardian: self.creator.key(),
+            reward_config,
+            rewards_allocated: 0,
+            total_deposited: 0,
+            dissolved: false,
+            dissolved_slot: 0,
+            dissolution_pool: 0,
+            dissolution_weight: 0,
+            proposal_deposit: 0,
+            discussion_period: 0,
+            dissolution_recipient: Pubkey::default(),
+            dissolved_at: 0,
         });
+
+        // Register the dao in the daoverse
+        self.dao_index.set_inner(DaoIndex {
+            index: self.daoverse.dao_count,
+            dao: self.dao.key(),
+            bump: bumps.dao_index,
+        });
+        self.daoverse.dao_count = self
+            .daoverse
+            .dao_count
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        emit!(DaoCreated {
+            dao: self.dao.key(),
+            creator: self.creator.key(),
+            dao_mint: self.dao_mint.key(),
+            dao_seed,
+            dao_index: self.dao_index.index,
+            dao_name: self.dao.dao_name.clone(),
+        });
+
+        Ok(())
     }
 
     pub fn dao_creator_deposit(&mut self, amount: u64) -> Result<()> {
@@ -156,14 +248,11 @@ impl<'info> CreateDao<'info> {
 
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
 
-        transfer_checked(cpi_ctx, amount, self.daoverse_mint.decimals)?;
+        transfer_checked(cpi_ctx, amount, self.dao_mint.decimals)?;
 
-        // Update treasury balance in DaoverseConfig
-        self.dao.dao_treasury_balance = self
-            .dao
-            .dao_treasury_balance
-            .checked_add(amount)
-            .ok_or(ErrorCode::Overflow)?;
+        // Update treasury balance in DaoConfig from the treasury ata
+        self.dao_treasury.reload()?;
+        self.dao.dao_treasury_balance = self.dao_treasury.amount;
 
         Ok(())
     }
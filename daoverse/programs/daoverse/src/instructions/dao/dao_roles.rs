@@ -0,0 +1,85 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// Accounts for creating the role registry of a dao
+#[derive(Accounts)]
+pub struct InitRoles<'info> {
+    // pays for the registry, the dao pda cannot pay for accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // dao creator until the dao is live, then the dao itself through an executed proposal instruction
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+        constraint = (dao.dao_creator == authority.key() && !dao.is_live()) || dao.key() == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RoleRegistry::INIT_SPACE,
+        seeds = [b"roles", dao.key().as_ref()],
+        bump,
+    )]
+    pub roles: Box<Account<'info, RoleRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitRoles<'info> {
+    pub fn init_roles(&mut self, bumps: InitRolesBumps) -> Result<()> {
+        self.roles.set_inner(RoleRegistry {
+            dao: self.dao.key(),
+            bump: bumps.roles,
+            council: Vec::new(),
+            guardians: Vec::new(),
+            treasurers: Vec::new(),
+        });
+
+        Ok(())
+    }
+}
+
+// Accounts for granting and revoking dao roles
+#[derive(Accounts)]
+pub struct UpdateRoles<'info> {
+    // dao creator until the dao is live, then the dao itself through an executed proposal instruction
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+        constraint = (dao.dao_creator == authority.key() && !dao.is_live()) || dao.key() == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"roles", dao.key().as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, RoleRegistry>>,
+}
+
+impl<'info> UpdateRoles<'info> {
+    // spending limit and period only apply to treasurers
+    pub fn grant_role(
+        &mut self,
+        holder: Pubkey,
+        role: DaoRole,
+        spending_limit: u64,
+        spending_period: u64,
+    ) -> Result<()> {
+        self.roles
+            .grant(holder, role, spending_limit, spending_period)
+    }
+
+    pub fn revoke_role(&mut self, holder: Pubkey, role: DaoRole) -> Result<()> {
+        self.roles.revoke(&holder, role)
+    }
+}
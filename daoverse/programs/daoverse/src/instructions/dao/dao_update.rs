@@ -1,22 +1,31 @@
 use crate::errors::ErrorCode;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct UpdateDao<'info> {
+    // dao creator, or the dao itself through an executed proposal instruction
     #[account(mut)]
     pub creator: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"dao", creator.key().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        seeds = [b"dao", dao.dao_creator.as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
         bump = dao.bump,
-        constraint = dao.dao_creator == creator.key()
+        constraint = dao.dao_creator == creator.key() || dao.key() == creator.key() @ ErrorCode::Unauthorized
     )]
     pub dao: Box<Account<'info, DaoConfig>>,
 }
 
 impl<'info> UpdateDao<'info> {
+    // the creator until the dao is live, then only the dao itself
+    fn governance_authorized(&self) -> bool {
+        let authority = self.creator.key();
+        (self.dao.dao_creator == authority && !self.dao.is_live()) || self.dao.key() == authority
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn update_dao(
         &mut self,
         dao_name: Option<String>,
@@ -25,12 +34,29 @@ impl<'info> UpdateDao<'info> {
         voting_model: Option<dao_config::VotingModel>,
         reward_model: Option<dao_config::RewardModel>,
         voting_threshold: Option<dao_config::VotingThreshold>,
+        eligibility_threshold: Option<dao_config::EligibilityThreshold>,
+        guardian: Option<Pubkey>,
+        reward_config: Option<dao_config::RewardConfig>,
+        proposal_deposit: Option<u64>,
+        discussion_period: Option<u64>,
     ) -> Result<()> {
+        // once live, every governance rule is changed by the dao itself;
+        // the creator keeps the name and description
+        let rules_changed = governance_model.is_some()
+            || voting_model.is_some()
+            || reward_model.is_some()
+            || voting_threshold.is_some()
+            || eligibility_threshold.is_some()
+            || guardian.is_some()
+            || reward_config.is_some()
+            || proposal_deposit.is_some()
+            || discussion_period.is_some();
+        require!(
+            !rules_changed || self.governance_authorized(),
+            ErrorCode::Unauthorized
+        );
         let dao = &mut self.dao;
 
-        //Enforce that the creator is the dao_creator
-        require_keys_eq!(self.creator.key(), dao.dao_creator, ErrorCode::Unauthorized);
-
         // Update fields if new values are provided
         if let Some(name) = dao_name {
             require!(name.len() <= 32, ErrorCode::StringTooLong);
@@ -68,10 +94,35 @@ impl<'info> UpdateDao<'info> {
                 threshold.min_voting_period <= threshold.max_voting_period,
                 ErrorCode::InvalidVotingPeriod
             );
-
             dao.voting_threshold = threshold;
         }
 
+        if let Some(eligibility) = eligibility_threshold {
+            dao.eligibility_threshold = eligibility;
+        }
+
+        if let Some(guardian) = guardian {
+            dao.guardian = guardian;
+        }
+
+        if let Some(rewards) = reward_config {
+            require!(rewards.is_valid(), ErrorCode::InvalidRewardConfig);
+            dao.reward_config = rewards;
+        }
+
+        if let Some(deposit) = proposal_deposit {
+            dao.proposal_deposit = deposit;
+        }
+
+        if let Some(period) = discussion_period {
+            dao.discussion_period = period;
+        }
+
+        emit!(DaoUpdated {
+            dao: dao.key(),
+            authority: self.creator.key(),
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,117 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+// Accounts for depositing any token into a dao treasury
+#[derive(Accounts)]
+pub struct DepositToTreasury<'info> {
+    // anyone can fund a dao
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    // the dao mint or any other mint the dao holds
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    // one treasury ata per mint, owned by the dao pda
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = mint,
+        associated_token::authority = dao,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DepositToTreasury<'info> {
+    pub fn deposit_to_treasury(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.depositor_ata.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to: self.treasury.to_account_info(),
+                    authority: self.depositor.to_account_info(),
+                },
+            ),
+            amount,
+            self.mint.decimals,
+        )?;
+
+        // the tracked balance only follows the dao mint treasury
+        if self.mint.key() == self.dao.dao_mint {
+            self.treasury.reload()?;
+            self.dao.dao_treasury_balance = self.treasury.amount;
+        }
+
+        Ok(())
+    }
+}
+
+// Accounts for depositing SOL into a dao treasury
+#[derive(Accounts)]
+pub struct DepositSolToTreasury<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    // system owned pda holding the dao's SOL
+    #[account(
+        mut,
+        seeds = [b"sol_treasury", dao.key().as_ref()],
+        bump,
+    )]
+    pub sol_treasury: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositSolToTreasury<'info> {
+    pub fn deposit_sol_to_treasury(&mut self, lamports: u64) -> Result<()> {
+        require!(lamports > 0, ErrorCode::InvalidAmount);
+
+        transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.depositor.to_account_info(),
+                    to: self.sol_treasury.to_account_info(),
+                },
+            ),
+            lamports,
+        )
+    }
+}
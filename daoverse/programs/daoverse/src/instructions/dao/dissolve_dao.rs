@@ -0,0 +1,56 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+// Accounts for dissolving a dao
+// the dao pda must sign, which only happens through an executed proposal instruction
+#[derive(Accounts)]
+pub struct DissolveDao<'info> {
+    #[account(
+        mut,
+        signer @ ErrorCode::Unauthorized,
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = dao_mint,
+        associated_token::authority = dao,
+    )]
+    pub dao_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DissolveDao<'info> {
+    // Freeze the dao and set its treasury aside for depositors
+    // the recipient gets what depositors cannot claim
+    pub fn dissolve_dao(&mut self, recipient: Pubkey) -> Result<()> {
+        require!(!self.dao.dissolved, ErrorCode::DaoDissolved);
+
+        let clock = Clock::get()?;
+        self.dao.dissolved = true;
+        self.dao.dissolved_slot = clock.slot;
+        self.dao.dissolved_at = clock.unix_timestamp;
+        self.dao.dissolution_recipient = recipient;
+        self.dao.dissolution_pool = self.dao_treasury.amount;
+        self.dao.dissolution_weight = self.dao.total_deposited;
+        self.dao.dao_treasury_balance = self.dao_treasury.amount;
+
+        emit!(DaoDissolved {
+            dao: self.dao.key(),
+            dissolution_pool: self.dao.dissolution_pool,
+            dissolution_weight: self.dao.dissolution_weight,
+            recipient,
+        });
+
+        Ok(())
+    }
+}
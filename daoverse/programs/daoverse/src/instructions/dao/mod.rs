@@ -1,5 +1,21 @@
+pub mod claim_dissolution;
 pub mod dao_creation;
+pub mod dao_roles;
 pub mod dao_update;
+pub mod deposit_treasury;
+pub mod dissolve_dao;
+pub mod sweep_dissolution;
+pub mod sync_treasury;
+pub mod treasurer_withdraw;
+pub mod withdraw_treasury;
 
+pub use claim_dissolution::*;
 pub use dao_creation::*;
+pub use dao_roles::*;
 pub use dao_update::*;
+pub use deposit_treasury::*;
+pub use dissolve_dao::*;
+pub use sweep_dissolution::*;
+pub use sync_treasury::*;
+pub use treasurer_withdraw::*;
+pub use withdraw_treasury::*;
@@ -0,0 +1,135 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+// Accounts for sweeping what depositors cannot claim from a dissolved dao
+#[derive(Accounts)]
+pub struct SweepDissolution<'info> {
+    // anyone can sweep, they only pay for the recipient ata if needed
+    #[account(mut)]
+    pub sweeper: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    // any mint the dao holds, only the dao mint is shared among depositors
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = dao,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_treasury", dao.key().as_ref()],
+        bump,
+    )]
+    pub sol_treasury: SystemAccount<'info>,
+
+    /// CHECK: receives the swept sol and owns the recipient ata, checked against the dao
+    #[account(mut, address = dao.dissolution_recipient @ ErrorCode::InvalidRecipient)]
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = sweeper,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SweepDissolution<'info> {
+    // Other mints can be swept right after dissolution, the dao mint and sol
+    // once every deposit has claimed or the claim period is over
+    pub fn sweep_dissolution(&mut self, bumps: SweepDissolutionBumps) -> Result<()> {
+        require!(self.dao.dissolved, ErrorCode::DaoNotDissolved);
+
+        let claims_open = self
+            .dao
+            .dissolution_claims_open(Clock::get()?.unix_timestamp);
+        let is_dao_mint = self.mint.key() == self.dao.dao_mint;
+        require!(
+            !(claims_open && is_dao_mint),
+            ErrorCode::DissolutionClaimsOpen
+        );
+
+        let amount = self.treasury.amount;
+        if amount > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.treasury.to_account_info(),
+                        mint: self.mint.to_account_info(),
+                        to: self.recipient_ata.to_account_info(),
+                        authority: self.dao.to_account_info(),
+                    },
+                    &[&[
+                        b"dao",
+                        self.dao.dao_creator.to_bytes().as_ref(),
+                        self.dao.dao_seed.to_le_bytes().as_ref(),
+                        &[self.dao.bump],
+                    ]],
+                ),
+                amount,
+                self.mint.decimals,
+            )?;
+        }
+
+        let lamports = if claims_open {
+            0
+        } else {
+            self.sol_treasury.lamports()
+        };
+        if lamports > 0 {
+            let dao_key = self.dao.key();
+            transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.sol_treasury.to_account_info(),
+                        to: self.recipient.to_account_info(),
+                    },
+                    &[&[b"sol_treasury", dao_key.as_ref(), &[bumps.sol_treasury]]],
+                ),
+                lamports,
+            )?;
+        }
+
+        if is_dao_mint {
+            self.treasury.reload()?;
+            self.dao.dao_treasury_balance = self.treasury.amount;
+        }
+
+        emit!(DissolutionSwept {
+            dao: self.dao.key(),
+            mint: self.mint.key(),
+            recipient: self.recipient.key(),
+            amount,
+            lamports,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+// Accounts for reconciling the tracked treasury balance with the treasury ata
+#[derive(Accounts)]
+pub struct SyncTreasury<'info> {
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        associated_token::mint = dao_mint,
+        associated_token::authority = dao,
+        associated_token::token_program = token_program,
+    )]
+    pub dao_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SyncTreasury<'info> {
+    // picks up tokens sent to the treasury ata directly
+    pub fn sync_treasury(&mut self) -> Result<()> {
+        self.dao.dao_treasury_balance = self.dao_treasury.amount;
+
+        Ok(())
+    }
+}
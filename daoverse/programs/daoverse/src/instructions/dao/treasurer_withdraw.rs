@@ -0,0 +1,94 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+// Accounts for a treasurer spending from the dao treasury
+#[derive(Accounts)]
+pub struct TreasurerWithdraw<'info> {
+    pub treasurer: Signer<'info>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"roles", dao.key().as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Box<Account<'info, RoleRegistry>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = dao,
+    )]
+    pub dao_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = dao_mint,
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> TreasurerWithdraw<'info> {
+    // Spend from the dao mint treasury within the treasurer's allowance
+    pub fn treasurer_withdraw(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(!self.dao.dissolved, ErrorCode::DaoDissolved);
+        require!(
+            self.dao_treasury.amount >= amount,
+            ErrorCode::InsufficientFunds
+        );
+
+        self.roles
+            .record_spend(&self.treasurer.key(), amount, Clock::get()?.unix_timestamp)?;
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.dao_treasury.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to: self.recipient_ata.to_account_info(),
+                    authority: self.dao.to_account_info(),
+                },
+                &[&[
+                    b"dao",
+                    self.dao.dao_creator.to_bytes().as_ref(),
+                    self.dao.dao_seed.to_le_bytes().as_ref(),
+                    &[self.dao.bump],
+                ]],
+            ),
+            amount,
+            self.dao_mint.decimals,
+        )?;
+
+        self.dao_treasury.reload()?;
+        self.dao.dao_treasury_balance = self.dao_treasury.amount;
+
+        emit!(TreasuryWithdrawn {
+            dao: self.dao.key(),
+            mint: Some(self.dao_mint.key()),
+            recipient: self.recipient_ata.key(),
+            amount,
+            authority: self.treasurer.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+// Accounts for withdrawing tokens from a dao treasury
+// the dao pda must sign, which only happens through an executed proposal instruction
+#[derive(Accounts)]
+pub struct WithdrawFromTreasury<'info> {
+    #[account(
+        mut,
+        signer @ ErrorCode::Unauthorized,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = dao,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawFromTreasury<'info> {
+    pub fn withdraw_from_treasury(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        // the dao mint treasury of a dissolved dao belongs to its depositors
+        require!(
+            !(self.dao.dissolved && self.mint.key() == self.dao.dao_mint),
+            ErrorCode::DaoDissolved
+        );
+        require!(self.treasury.amount >= amount, ErrorCode::InsufficientFunds);
+
+        // the dao signature is carried over from the proposal instruction
+        transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.treasury.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to: self.recipient_ata.to_account_info(),
+                    authority: self.dao.to_account_info(),
+                },
+            ),
+            amount,
+            self.mint.decimals,
+        )?;
+
+        if self.mint.key() == self.dao.dao_mint {
+            self.treasury.reload()?;
+            self.dao.dao_treasury_balance = self.treasury.amount;
+        }
+
+        emit!(TreasuryWithdrawn {
+            dao: self.dao.key(),
+            mint: Some(self.mint.key()),
+            recipient: self.recipient_ata.key(),
+            amount,
+            authority: self.dao.key(),
+        });
+
+        Ok(())
+    }
+}
+
+// Accounts for withdrawing SOL from a dao treasury, gated like token withdrawals
+#[derive(Accounts)]
+pub struct WithdrawSolFromTreasury<'info> {
+    #[account(
+        signer @ ErrorCode::Unauthorized,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_treasury", dao.key().as_ref()],
+        bump,
+    )]
+    pub sol_treasury: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawSolFromTreasury<'info> {
+    pub fn withdraw_sol_from_treasury(
+        &mut self,
+        bumps: WithdrawSolFromTreasuryBumps,
+        lamports: u64,
+    ) -> Result<()> {
+        require!(lamports > 0, ErrorCode::InvalidAmount);
+        // the sol treasury of a dissolved dao belongs to its depositors
+        require!(!self.dao.dissolved, ErrorCode::DaoDissolved);
+
+        // a partial withdrawal must leave the treasury rent exempt
+        let balance = self.sol_treasury.lamports();
+        require!(balance >= lamports, ErrorCode::InsufficientFunds);
+        require!(
+            lamports == balance || balance - lamports >= Rent::get()?.minimum_balance(0),
+            ErrorCode::InsufficientFunds
+        );
+
+        let dao_key = self.dao.key();
+        transfer(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.sol_treasury.to_account_info(),
+                    to: self.recipient.to_account_info(),
+                },
+                &[&[b"sol_treasury", dao_key.as_ref(), &[bumps.sol_treasury]]],
+            ),
+            lamports,
+        )?;
+
+        emit!(TreasuryWithdrawn {
+            dao: dao_key,
+            mint: None,
+            recipient: self.recipient.key(),
+            amount: lamports,
+            authority: dao_key,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// Accounts for delegating voting power
+#[derive(Accounts)]
+#[instruction(dao_scope: Pubkey)]
+pub struct DelegateVotes<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    /// CHECK: any wallet can be a delegate, membership is checked when it votes
+    pub delegate: UncheckedAccount<'info>,
+
+    // one delegation per delegator and dao scope
+    #[account(
+        init,
+        payer = delegator,
+        space = 8 + DelegationState::INIT_SPACE,
+        seeds = [b"delegation", delegator.key().as_ref(), dao_scope.as_ref()],
+        bump,
+    )]
+    pub delegation: Box<Account<'info, DelegationState>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DelegateVotes<'info> {
+    pub fn delegate_votes(&mut self, bumps: DelegateVotesBumps, dao_scope: Pubkey) -> Result<()> {
+        require_keys_neq!(
+            self.delegator.key(),
+            self.delegate.key(),
+            ErrorCode::InvalidDelegation
+        );
+
+        self.delegation.set_inner(DelegationState {
+            delegator: self.delegator.key(),
+            delegate: self.delegate.key(),
+            dao: dao_scope,
+            bump: bumps.delegation,
+            delegated_at: Clock::get()?.unix_timestamp,
+        });
+
+        emit!(VotesDelegated {
+            delegator: self.delegator.key(),
+            delegate: self.delegate.key(),
+            dao_scope,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod delegate_votes;
+pub mod revoke_delegation;
+
+pub use delegate_votes::*;
+pub use revoke_delegation::*;
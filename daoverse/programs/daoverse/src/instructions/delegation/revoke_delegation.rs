@@ -0,0 +1,33 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// Accounts for revoking a delegation, rent goes back to the delegator
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    #[account(
+        mut,
+        close = delegator,
+        seeds = [b"delegation", delegator.key().as_ref(), delegation.dao.as_ref()],
+        bump = delegation.bump,
+        constraint = delegation.delegator == delegator.key() @ ErrorCode::Unauthorized,
+    )]
+    pub delegation: Box<Account<'info, DelegationState>>,
+}
+
+impl<'info> RevokeDelegation<'info> {
+    // votes already cast by the delegate keep counting
+    pub fn revoke_delegation(&mut self) -> Result<()> {
+        emit!(DelegationRevoked {
+            delegator: self.delegator.key(),
+            delegate: self.delegation.delegate,
+            dao_scope: self.delegation.dao,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+// Accounts for depositing governance tokens that count as voting power
+#[derive(Accounts)]
+pub struct DepositGovernance<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    // only members of the dao can deposit
+    #[account(
+        seeds = [b"member", dao.key().as_ref(), owner.key().as_ref()],
+        bump = member.bump,
+        constraint = member.dao_member == owner.key() @ ErrorCode::Unauthorized,
+        constraint = member.dao_joined == dao.key() @ ErrorCode::MemberRequired,
+    )]
+    pub member: Box<Account<'info, DaoMemberState>>,
+
+    // one deposit per wallet and dao
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + GovernanceDepositState::INIT_SPACE,
+        seeds = [b"governance", dao.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub governance_deposit: Box<Account<'info, GovernanceDepositState>>,
+
+    // holds the deposited tokens
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = dao_mint,
+        associated_token::authority = governance_deposit,
+    )]
+    pub governance_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_dao_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DepositGovernance<'info> {
+    pub fn deposit_governance(&mut self, bumps: DepositGovernanceBumps, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        // dissolution shares are fixed once the dao is dissolved
+        require!(!self.dao.dissolved, ErrorCode::DaoDissolved);
+
+        // first deposit
+        if self.governance_deposit.owner == Pubkey::default() {
+            self.governance_deposit.set_inner(GovernanceDepositState {
+                owner: self.owner.key(),
+                dao: self.dao.key(),
+                bump: bumps.governance_deposit,
+                amount: 0,
+                checkpoints: Vec::new(),
+                dissolution_claimed: false,
+            });
+        }
+
+        transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.owner_dao_ata.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to: self.governance_vault.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            amount,
+            self.dao_mint.decimals,
+        )?;
+
+        self.governance_deposit.amount = self
+            .governance_deposit
+            .amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+        self.governance_deposit.checkpoint(Clock::get()?.slot);
+
+        self.dao.total_deposited = self
+            .dao
+            .total_deposited
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+
+        Ok(())
+    }
+}
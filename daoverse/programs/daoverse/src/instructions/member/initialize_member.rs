@@ -1,4 +1,5 @@
 use crate::errors::ErrorCode;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
@@ -7,24 +8,30 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-#[derive(Accounts)]
-#[instruction(member_seed: u64)]
-
 //to get the user's dao balance, we need the ata of the dao mint
 //how does the user now join the dao? - by having a sufficient amount of the dao_mint
-
+#[derive(Accounts)]
 pub struct InitializeMember<'info> {
     //member
     #[account(mut)]
     pub user: Signer<'info>,
     pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    //dao member state
+    //dao
+    #[account(
+        mut,
+        has_one=dao_mint,
+        seeds=[b"dao", dao.dao_creator.key().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump=dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    //dao member state, one membership per wallet and dao
     #[account(
         init,
         payer=user,
         space=8+DaoMemberState::INIT_SPACE,
-        seeds=[b"member", user.key().as_ref(), member_seed.to_le_bytes().as_ref()],
+        seeds=[b"member", dao.key().as_ref(), user.key().as_ref()],
         bump,
     )]
     pub member: Box<Account<'info, DaoMemberState>>,
@@ -37,15 +44,6 @@ pub struct InitializeMember<'info> {
     )]
     pub member_dao_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    //dao
-    #[account(
-        mut,
-        has_one=dao_mint,
-        seeds=[b"dao", dao.dao_creator.key().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
-        bump=dao.bump,
-    )]
-    pub dao: Box<Account<'info, DaoConfig>>,
-
     //dao treasury? - not needed since transfer is not made
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -63,29 +61,35 @@ impl<'info> InitializeMember<'info> {
         if self.member_dao_ata.mint != self.dao_mint.key() {
             return err!(ErrorCode::InvalidDaoMint);
         }
-        // Validate member has sufficient DAO tokens (>100) AND correct mint
-        if self.member_dao_ata.amount < 100 {
+        // Validate member holds the dao's minimum member balance
+        let min_member_amount = to_base_units(
+            self.dao.eligibility_threshold.min_member_tokens,
+            self.dao_mint.decimals,
+        )
+        .ok_or(ErrorCode::CalculationError)?;
+        if self.member_dao_ata.amount < min_member_amount {
             return err!(ErrorCode::InsufficientDaoTokens);
         }
 
         Ok(())
     }
 
-    pub fn initialize_member(
-        &mut self,
-        bumps: InitializeMemberBumps,
-        member_seed: u64,
-    ) -> Result<()> {
+    pub fn initialize_member(&mut self, bumps: InitializeMemberBumps) -> Result<()> {
         self.member.set_inner(DaoMemberState {
-            member_seed,
             dao_member: self.user.key(),
             bump: bumps.member,
+            member_name: String::new(),
+            member_description: String::new(),
             created_proposals: 0,
             approved_proposals: 0,
             total_rewards: 0,
             total_votes: 0,
             dao_member_balance: self.member_dao_ata.amount,
             dao_joined: self.dao.key(),
+            joined_at: Clock::get()?.unix_timestamp,
+            locked_amount: 0,
+            lock_end: 0,
+            lock_checkpoints: Vec::new(),
         });
 
         // Increment member count in DAO
@@ -95,6 +99,13 @@ impl<'info> InitializeMember<'info> {
             .checked_add(1)
             .ok_or(ErrorCode::Overflow)?;
 
+        emit!(MemberJoined {
+            dao: self.dao.key(),
+            member: self.member.key(),
+            user: self.user.key(),
+            joined_at: self.member.joined_at,
+        });
+
         Ok(())
     }
 
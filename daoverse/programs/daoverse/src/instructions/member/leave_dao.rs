@@ -0,0 +1,49 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// Accounts for leaving a dao and reclaiming the membership rent
+#[derive(Accounts)]
+pub struct LeaveDao<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"member", dao.key().as_ref(), user.key().as_ref()],
+        bump = member.bump,
+        constraint = member.dao_member == user.key() @ ErrorCode::Unauthorized,
+        constraint = member.dao_joined == dao.key() @ ErrorCode::MemberRequired,
+    )]
+    pub member: Box<Account<'info, DaoMemberState>>,
+}
+
+impl<'info> LeaveDao<'info> {
+    pub fn leave_dao(&mut self) -> Result<()> {
+        // locked tokens are held by the membership, unlock them first
+        require!(self.member.locked_amount == 0, ErrorCode::LockNotExpired);
+
+        self.dao.member_count = self
+            .dao
+            .member_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        emit!(MemberLeft {
+            dao: self.dao.key(),
+            member: self.member.key(),
+            user: self.user.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+// Accounts for locking dao tokens in the member's vote-escrow
+#[derive(Accounts)]
+pub struct LockTokens<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    // the lock is kept on the membership
+    #[account(
+        mut,
+        seeds = [b"member", dao.key().as_ref(), owner.key().as_ref()],
+        bump = member.bump,
+        constraint = member.dao_member == owner.key() @ ErrorCode::Unauthorized,
+        constraint = member.dao_joined == dao.key() @ ErrorCode::MemberRequired,
+    )]
+    pub member: Box<Account<'info, DaoMemberState>>,
+
+    // holds the locked tokens until unlock
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = dao_mint,
+        associated_token::authority = member,
+    )]
+    pub escrow_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_dao_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> LockTokens<'info> {
+    // Add tokens to the lock and/or extend it, a lock never ends earlier than before
+    pub fn lock_tokens(&mut self, amount: u64, lock_duration: i64) -> Result<()> {
+        require!(!self.dao.dissolved, ErrorCode::DaoDissolved);
+        require!(
+            (MIN_LOCK_DURATION..=MAX_LOCK_DURATION).contains(&lock_duration),
+            ErrorCode::InvalidLockDuration
+        );
+        require!(
+            amount > 0 || self.member.locked_amount > 0,
+            ErrorCode::InvalidAmount
+        );
+
+        let clock = Clock::get()?;
+        let lock_end = clock
+            .unix_timestamp
+            .checked_add(lock_duration)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        if amount > 0 {
+            transfer_checked(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.owner_dao_ata.to_account_info(),
+                        mint: self.dao_mint.to_account_info(),
+                        to: self.escrow_vault.to_account_info(),
+                        authority: self.owner.to_account_info(),
+                    },
+                ),
+                amount,
+                self.dao_mint.decimals,
+            )?;
+        }
+
+        self.member.locked_amount = self
+            .member
+            .locked_amount
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+        self.member.lock_end = self.member.lock_end.max(lock_end);
+        self.member.checkpoint_lock(clock.slot);
+
+        Ok(())
+    }
+}
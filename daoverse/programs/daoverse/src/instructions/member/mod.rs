@@ -1,5 +1,15 @@
+pub mod deposit_governance;
 pub mod initialize_member;
+pub mod leave_dao;
+pub mod lock_tokens;
+pub mod unlock_tokens;
 pub mod update_member;
+pub mod withdraw_governance;
 
+pub use deposit_governance::*;
 pub use initialize_member::*;
+pub use leave_dao::*;
+pub use lock_tokens::*;
+pub use unlock_tokens::*;
 pub use update_member::*;
+pub use withdraw_governance::*;
@@ -0,0 +1,90 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+// Accounts for releasing an expired vote-escrow lock
+#[derive(Accounts)]
+pub struct UnlockTokens<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"member", dao.key().as_ref(), owner.key().as_ref()],
+        bump = member.bump,
+        constraint = member.dao_member == owner.key() @ ErrorCode::Unauthorized,
+        constraint = member.dao_joined == dao.key() @ ErrorCode::MemberRequired,
+    )]
+    pub member: Box<Account<'info, DaoMemberState>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = member,
+    )]
+    pub escrow_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_dao_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> UnlockTokens<'info> {
+    // Return the whole lock once it has expired, or right away in a dissolved dao
+    pub fn unlock_tokens(&mut self) -> Result<()> {
+        let amount = self.member.locked_amount;
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        let clock = Clock::get()?;
+        require!(
+            self.dao.dissolved || clock.unix_timestamp >= self.member.lock_end,
+            ErrorCode::LockNotExpired
+        );
+
+        let owner_key = self.owner.key();
+        let dao_key = self.dao.key();
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.escrow_vault.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to: self.owner_dao_ata.to_account_info(),
+                    authority: self.member.to_account_info(),
+                },
+                &[&[
+                    b"member",
+                    dao_key.as_ref(),
+                    owner_key.as_ref(),
+                    &[self.member.bump],
+                ]],
+            ),
+            amount,
+            self.dao_mint.decimals,
+        )?;
+
+        self.member.locked_amount = 0;
+        self.member.lock_end = 0;
+        self.member.checkpoint_lock(clock.slot);
+
+        Ok(())
+    }
+}
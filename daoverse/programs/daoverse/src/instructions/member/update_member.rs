@@ -9,7 +9,7 @@ pub struct UpdateMember<'info> {
 
     #[account(
         mut,
-        seeds = [b"member", user.key().as_ref(), member.member_seed.to_le_bytes().as_ref()],
+        seeds = [b"member", member.dao_joined.as_ref(), user.key().as_ref()],
         bump = member.bump,
         constraint = member.dao_member == user.key() @ ErrorCode::Unauthorized
     )]
@@ -17,29 +17,25 @@ pub struct UpdateMember<'info> {
 }
 
 impl<'info> UpdateMember<'info> {
+    // participation stats are maintained by the program, members only edit their profile
     pub fn update_member(
         &mut self,
-        created_proposals: Option<u64>,
-        approved_proposals: Option<u64>,
-        total_rewards: Option<u64>,
-        total_votes: Option<u64>,
+        member_name: Option<String>,
+        member_description: Option<String>,
     ) -> Result<()> {
         let member = &mut self.member;
 
-        if let Some(proposals) = created_proposals {
-            member.created_proposals = proposals;
+        if let Some(name) = member_name {
+            require!(name.len() <= MAX_MEMBER_NAME_LEN, ErrorCode::StringTooLong);
+            member.member_name = name;
         }
 
-        if let Some(approved) = approved_proposals {
-            member.approved_proposals = approved;
-        }
-
-        if let Some(rewards) = total_rewards {
-            member.total_rewards = rewards;
-        }
-
-        if let Some(votes) = total_votes {
-            member.total_votes = votes;
+        if let Some(description) = member_description {
+            require!(
+                description.len() <= MAX_MEMBER_DESCRIPTION_LEN,
+                ErrorCode::StringTooLong
+            );
+            member.member_description = description;
         }
 
         Ok(())
@@ -0,0 +1,90 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+// Accounts for withdrawing deposited governance tokens
+#[derive(Accounts)]
+pub struct WithdrawGovernance<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"governance", dao.key().as_ref(), owner.key().as_ref()],
+        bump = governance_deposit.bump,
+    )]
+    pub governance_deposit: Box<Account<'info, GovernanceDepositState>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = governance_deposit,
+    )]
+    pub governance_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_dao_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawGovernance<'info> {
+    // voting power already snapshotted by open proposals is not affected
+    pub fn withdraw_governance(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        self.governance_deposit.amount = self
+            .governance_deposit
+            .amount
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFunds)?;
+        self.governance_deposit.checkpoint(Clock::get()?.slot);
+        self.dao.total_deposited = self
+            .dao
+            .total_deposited
+            .checked_sub(amount)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        let dao_key = self.dao.key();
+        let owner_key = self.owner.key();
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.governance_vault.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to: self.owner_dao_ata.to_account_info(),
+                    authority: self.governance_deposit.to_account_info(),
+                },
+                &[&[
+                    b"governance",
+                    dao_key.as_ref(),
+                    owner_key.as_ref(),
+                    &[self.governance_deposit.bump],
+                ]],
+            ),
+            amount,
+            self.dao_mint.decimals,
+        )?;
+
+        Ok(())
+    }
+}
@@ -12,3 +12,6 @@ pub use proposal::*;
 
 pub mod vote;
 pub use vote::*;
+
+pub mod delegation;
+pub use delegation::*;
@@ -0,0 +1,82 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// Accounts for attaching an instruction to a proposal
+#[derive(Accounts)]
+pub struct AddProposalInstruction<'info> {
+    // only the proposal owner can attach instructions
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.proposal_owner == proposer.key() @ ErrorCode::Unauthorized,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    // next instruction slot of the proposal
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + ProposalInstructionState::INIT_SPACE,
+        seeds = [b"proposal_instruction", proposal.key().as_ref(), proposal.instruction_count.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal_instruction: Box<Account<'info, ProposalInstructionState>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddProposalInstruction<'info> {
+    pub fn add_instruction(
+        &mut self,
+        bumps: AddProposalInstructionBumps,
+        program_id: Pubkey,
+        accounts: Vec<InstructionAccount>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        // voters must see every instruction they vote on
+        require!(
+            matches!(
+                self.proposal.status,
+                ProposalStatus::Draft | ProposalStatus::Active
+            ),
+            ErrorCode::ProposalNotActive
+        );
+        require!(
+            !self.proposal.is_multi_choice(),
+            ErrorCode::MultiChoicePayload
+        );
+        require!(
+            !self.proposal.voting_started && self.proposal.open_vote_records == 0,
+            ErrorCode::VotingStarted
+        );
+        require!(
+            accounts.len() <= MAX_INSTRUCTION_ACCOUNTS && data.len() <= MAX_INSTRUCTION_DATA_LEN,
+            ErrorCode::InstructionTooLarge
+        );
+
+        self.proposal_instruction
+            .set_inner(ProposalInstructionState {
+                proposal: self.proposal.key(),
+                proposal_index: self.proposal.proposal_index,
+                index: self.proposal.instruction_count,
+                program_id,
+                accounts,
+                data,
+                executed: false,
+                bump: bumps.proposal_instruction,
+            });
+
+        self.proposal.instruction_count = self
+            .proposal
+            .instruction_count
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        Ok(())
+    }
+}
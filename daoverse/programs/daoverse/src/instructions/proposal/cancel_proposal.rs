@@ -0,0 +1,70 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// Accounts for cancelling a proposal
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    // proposal owner while in draft, a guardian until queued
+    // and a council member once the proposal succeeded
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    #[account(
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    // role registry of the dao, needed when cancelling through a registry role
+    #[account(
+        seeds = [b"roles", dao.key().as_ref()],
+        bump = roles.bump,
+    )]
+    pub roles: Option<Box<Account<'info, RoleRegistry>>>,
+}
+
+impl<'info> CancelProposal<'info> {
+    // voters reclaim their stake through claim_stake_rewards once cancelled
+    pub fn cancel_proposal(&mut self) -> Result<()> {
+        let authority = self.authority.key();
+        let has_role = |role| {
+            self.roles
+                .as_ref()
+                .is_some_and(|roles| roles.has_role(&authority, role))
+        };
+        let guardian = authority == self.dao.guardian || has_role(DaoRole::Guardian);
+        let council = has_role(DaoRole::Council);
+
+        let authorized = match self.proposal.status {
+            // once voting is open the owner can no longer pull the proposal,
+            // so the deposit stays at stake for the vote
+            ProposalStatus::Draft => authority == self.proposal.proposal_owner || guardian,
+            ProposalStatus::Active => guardian,
+            // council veto of an approved proposal
+            ProposalStatus::Succeeded => council,
+            ProposalStatus::Queued => guardian || council,
+            _ => return err!(ErrorCode::InvalidProposalStatus),
+        };
+        require!(authorized, ErrorCode::Unauthorized);
+
+        self.proposal.transition_to(ProposalStatus::Cancelled)?;
+
+        emit!(ProposalCancelled {
+            dao: self.dao.key(),
+            proposal: self.proposal.key(),
+            authority,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+// Accounts for closing a finished proposal and reclaiming its rent
+#[derive(Accounts)]
+pub struct CloseProposal<'info> {
+    #[account(mut)]
+    pub proposal_owner: Signer<'info>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        mut,
+        close = proposal_owner,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
+        has_one = proposal_owner @ ErrorCode::Unauthorized,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    // rounding dust left in the staking vault goes to the treasury
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = dao,
+    )]
+    pub dao_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // already closed by the last claim when it emptied the vault
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = proposal,
+    )]
+    pub staking_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CloseProposal<'info> {
+    // remaining accounts are all instruction records of the proposal in index order,
+    // none may outlive it or they would match a proposal re-created at the same address
+    pub fn close_proposal(
+        &mut self,
+        instruction_records: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(self.proposal.can_close(), ErrorCode::ProposalNotClosable);
+
+        match self.staking_vault.as_ref() {
+            Some(staking_vault) => self.close_staking_vault(staking_vault)?,
+            None => require!(
+                self.proposal.staking_vault_balance == 0,
+                ErrorCode::ProposalNotClosable
+            ),
+        }
+
+        require!(
+            instruction_records.len() == self.proposal.instruction_count as usize,
+            ErrorCode::InstructionRecordsRequired
+        );
+
+        let proposal_key = self.proposal.key();
+        for (index, record_info) in instruction_records.iter().enumerate() {
+            let record = Account::<ProposalInstructionState>::try_from(record_info)?;
+            require_keys_eq!(record.proposal, proposal_key, ErrorCode::InvalidProposal);
+            require!(
+                record.proposal_index == self.proposal.proposal_index
+                    && record.index as usize == index,
+                ErrorCode::InstructionRecordsRequired
+            );
+
+            // the proposal owner paid for the instruction records
+            record.close(self.proposal_owner.to_account_info())?;
+        }
+
+        Ok(())
+    }
+
+    fn close_staking_vault(
+        &self,
+        staking_vault: &InterfaceAccount<'info, TokenAccount>,
+    ) -> Result<()> {
+        let proposal_seeds: &[&[u8]] = &[
+            b"proposal",
+            self.proposal.dao.as_ref(),
+            &self.proposal.proposal_owner.to_bytes(),
+            &self.proposal.proposal_seed.to_le_bytes(),
+            &[self.proposal.bump],
+        ];
+
+        if staking_vault.amount > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: staking_vault.to_account_info(),
+                        mint: self.dao_mint.to_account_info(),
+                        to: self.dao_treasury.to_account_info(),
+                        authority: self.proposal.to_account_info(),
+                    },
+                    &[proposal_seeds],
+                ),
+                staking_vault.amount,
+                self.dao_mint.decimals,
+            )?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: staking_vault.to_account_info(),
+                destination: self.proposal_owner.to_account_info(),
+                authority: self.proposal.to_account_info(),
+            },
+            &[proposal_seeds],
+        ))
+    }
+}
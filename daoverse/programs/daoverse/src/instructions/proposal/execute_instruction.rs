@@ -0,0 +1,120 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+// Accounts for running an instruction of an executed proposal
+#[derive(Accounts)]
+pub struct ExecuteProposalInstruction<'info> {
+    // anyone can crank execution once the proposal is executed
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    // not mut so the dao is not written back over changes made by the instruction
+    #[account(
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal_instruction", proposal.key().as_ref(), proposal_instruction.index.to_le_bytes().as_ref()],
+        bump = proposal_instruction.bump,
+        has_one = proposal @ ErrorCode::InvalidProposal,
+        constraint = proposal_instruction.proposal_index == proposal.proposal_index @ ErrorCode::InvalidProposal,
+        constraint = !proposal_instruction.executed @ ErrorCode::InstructionAlreadyExecuted,
+    )]
+    pub proposal_instruction: Box<Account<'info, ProposalInstructionState>>,
+}
+
+impl<'info> ExecuteProposalInstruction<'info> {
+    // remaining accounts are the instruction accounts in order followed by the target program
+    pub fn execute_instruction(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        // the dao pda no longer signs once its treasury belongs to the depositors
+        require!(!self.dao.dissolved, ErrorCode::DaoDissolved);
+        require!(
+            self.proposal.status == ProposalStatus::Executed,
+            ErrorCode::InvalidProposalStatus
+        );
+        require!(
+            self.proposal.executed_instructions < self.proposal.instruction_count,
+            ErrorCode::InvalidInstructionIndex
+        );
+        require!(
+            self.proposal_instruction.index == self.proposal.executed_instructions,
+            ErrorCode::InvalidInstructionIndex
+        );
+
+        let instruction_accounts = &self.proposal_instruction.accounts;
+        require!(
+            remaining_accounts.len() > instruction_accounts.len(),
+            ErrorCode::InvalidInstructionAccounts
+        );
+
+        for (meta, account) in instruction_accounts.iter().zip(remaining_accounts) {
+            require_keys_eq!(
+                meta.pubkey,
+                account.key(),
+                ErrorCode::InvalidInstructionAccounts
+            );
+        }
+        require_keys_eq!(
+            remaining_accounts[instruction_accounts.len()].key(),
+            self.proposal_instruction.program_id,
+            ErrorCode::InvalidInstructionAccounts
+        );
+
+        let instruction = Instruction {
+            program_id: self.proposal_instruction.program_id,
+            accounts: instruction_accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: self.proposal_instruction.data.clone(),
+        };
+
+        // the dao pda signs for the instruction
+        invoke_signed(
+            &instruction,
+            remaining_accounts,
+            &[&[
+                b"dao",
+                self.dao.dao_creator.to_bytes().as_ref(),
+                self.dao.dao_seed.to_le_bytes().as_ref(),
+                &[self.dao.bump],
+            ]],
+        )?;
+
+        self.proposal_instruction.executed = true;
+        self.proposal.executed_instructions = self
+            .proposal
+            .executed_instructions
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        emit!(ProposalInstructionExecuted {
+            dao: self.dao.key(),
+            proposal: self.proposal.key(),
+            index: self.proposal_instruction.index,
+            program_id: self.proposal_instruction.program_id,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+// Accounts for executing a proposal once voting has ended
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    // anyone can crank execution, they only pay for the recipient ata if needed
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Proposal being executed
+    #[account(
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    #[account(
+        mut,
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    //dao treasury - vault
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = dao,
+    )]
+    pub dao_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: the proposal owner's member pda in the dao paying for the proposal.
+    /// It is only left empty once the owner has left the dao
+    #[account(
+        mut,
+        seeds = [b"member", dao.key().as_ref(), proposal.proposal_owner.as_ref()],
+        bump,
+    )]
+    pub owner_member: UncheckedAccount<'info>,
+
+    /// CHECK: only used as the authority of the recipient ata, checked against the proposal
+    #[account(address = proposal.recipient @ ErrorCode::InvalidRecipient)]
+    pub recipient: UncheckedAccount<'info>,
+
+    // recipient ata for dao mint
+    #[account(
+        init_if_needed,
+        payer = executor,
+        associated_token::mint = dao_mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_dao_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ExecuteProposal<'info> {
+    pub fn execute_proposal(&mut self) -> Result<()> {
+        // the treasury of a dissolved dao belongs to its depositors
+        require!(!self.dao.dissolved, ErrorCode::DaoDissolved);
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // Tally votes if no one has settled the proposal yet
+        if self.proposal.status == ProposalStatus::Active {
+            self.proposal.settle(self.dao.member_count, current_time)?;
+
+            // persist the defeat instead of failing the transaction
+            if self.proposal.status == ProposalStatus::Defeated {
+                return Ok(());
+            }
+        }
+
+        match self.proposal.status {
+            // proposals created with an execution delay must be queued first
+            ProposalStatus::Succeeded => require!(
+                self.proposal.execution_delay == 0,
+                ErrorCode::ProposalNotQueued
+            ),
+            ProposalStatus::Queued => require!(
+                current_time >= self.proposal.eta,
+                ErrorCode::TimelockNotElapsed
+            ),
+            _ => return err!(ErrorCode::InvalidProposalStatus),
+        }
+
+        // Approved proposals left unexecuted for too long can no longer move funds
+        if current_time > self.proposal.execution_deadline()? {
+            return self.proposal.transition_to(ProposalStatus::Expired);
+        }
+
+        let proposal_cost = self.proposal.proposal_cost;
+
+        // Check if dao treasury can pay for the proposal
+        if self.dao_treasury.amount < proposal_cost {
+            return err!(ErrorCode::InsufficientFunds);
+        }
+
+        // Transfer proposal cost from treasury to recipient
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.dao_treasury.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to: self.recipient_dao_ata.to_account_info(),
+                    authority: self.dao.to_account_info(),
+                },
+                &[&[
+                    b"dao",
+                    self.dao.dao_creator.to_bytes().as_ref(),
+                    self.dao.dao_seed.to_le_bytes().as_ref(),
+                    &[self.dao.bump],
+                ]],
+            ),
+            proposal_cost,
+            self.dao_mint.decimals,
+        )?;
+
+        // reconcile the tracked balance with the treasury ata
+        self.dao_treasury.reload()?;
+        self.dao.dao_treasury_balance = self.dao_treasury.amount;
+
+        self.dao.approved_proposals = self
+            .dao
+            .approved_proposals
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        // a closed member pda proves the owner left, there are no stats to update
+        if !self.owner_member.data_is_empty() {
+            let mut owner_member =
+                DaoMemberState::try_deserialize(&mut &self.owner_member.try_borrow_data()?[..])?;
+            owner_member.approved_proposals = owner_member
+                .approved_proposals
+                .checked_add(1)
+                .ok_or(ErrorCode::Overflow)?;
+            owner_member.try_serialize(&mut &mut self.owner_member.try_borrow_mut_data()?[..])?;
+        }
+
+        self.proposal.transition_to(ProposalStatus::Executed)?;
+
+        emit!(ProposalExecuted {
+            dao: self.dao.key(),
+            proposal: self.proposal.key(),
+            recipient: self.proposal.recipient,
+            proposal_cost,
+        });
+
+        Ok(())
+    }
+}
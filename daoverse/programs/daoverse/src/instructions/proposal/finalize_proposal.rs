@@ -0,0 +1,263 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+// Accounts for finalizing a proposal once voting has ended
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Proposal being finalized
+    #[account(
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    #[account(
+        mut,
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    //dao treasury - vault
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = dao,
+    )]
+    pub dao_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Staking vault from the proposal
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = proposal,
+    )]
+    pub staking_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // refund destination for the proposer deposit, only needed when it is returned
+    #[account(
+        mut,
+        constraint = proposer_dao_ata.owner == proposal.proposal_owner @ ErrorCode::InvalidRecipient,
+        constraint = proposer_dao_ata.mint == dao_mint.key() @ ErrorCode::InvalidDaoMint,
+    )]
+    pub proposer_dao_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> FinalizeProposal<'info> {
+    // Settle the proposal if needed and snapshot its reward pool and forfeits
+    // runs exactly once, claims only read the snapshot afterwards
+    pub fn finalize_proposal(&mut self) -> Result<()> {
+        require!(
+            !self.proposal.finalized,
+            ErrorCode::ProposalAlreadyFinalized
+        );
+
+        if !self.proposal.status.is_settled() {
+            // Check if voting period has ended and record the outcome
+            let current_time = Clock::get()?.unix_timestamp;
+            self.proposal.settle(self.dao.member_count, current_time)?;
+        }
+        self.proposal.finalized = true;
+        self.settle_deposit()?;
+
+        // cancelled proposals only refund the stake
+        if self.proposal.status != ProposalStatus::Cancelled {
+            self.fund_reward_pool()?;
+            self.settle_forfeits()?;
+        }
+
+        emit!(ProposalFinalized {
+            dao: self.dao.key(),
+            proposal: self.proposal.key(),
+            status: self.proposal.status,
+            quorum_reached: self.proposal.quorum_reached,
+            winning_vote: self.proposal.winning_vote,
+            reward_pool: self.proposal.reward_pool,
+            forfeited_stake: self.proposal.forfeited_stake,
+        });
+
+        Ok(())
+    }
+
+    // Return the proposer deposit, or slash it to the treasury without quorum
+    fn settle_deposit(&mut self) -> Result<()> {
+        let deposit = self.proposal.deposit;
+        if deposit == 0 {
+            return Ok(());
+        }
+
+        let slashed = self.proposal.deposit_slashed();
+        let to = if slashed {
+            self.dao_treasury.to_account_info()
+        } else {
+            self.proposer_dao_ata
+                .as_ref()
+                .ok_or(ErrorCode::InvalidRecipient)?
+                .to_account_info()
+        };
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.staking_vault.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to,
+                    authority: self.proposal.to_account_info(),
+                },
+                &[&[
+                    b"proposal",
+                    self.proposal.dao.as_ref(),
+                    self.proposal.proposal_owner.to_bytes().as_ref(),
+                    self.proposal.proposal_seed.to_le_bytes().as_ref(),
+                    &[self.proposal.bump],
+                ]],
+            ),
+            deposit,
+            self.dao_mint.decimals,
+        )?;
+        self.proposal.deposit = 0;
+
+        if slashed {
+            self.dao_treasury.reload()?;
+            self.dao.dao_treasury_balance = self.dao_treasury.amount;
+        }
+
+        Ok(())
+    }
+
+    // Move the proposal reward pool from the treasury to the staking vault
+    fn fund_reward_pool(&mut self) -> Result<()> {
+        if self.dao.reward_model == RewardModel::NoRewards
+            || self.proposal.total_reward_weight == 0
+            || self.dao.dissolved
+        {
+            return Ok(());
+        }
+
+        // Fixed reward pool, within the remaining budget and the treasury
+        let reward_pool = self
+            .dao
+            .reward_config
+            .reward_pool(self.dao.rewards_allocated)
+            .min(self.dao_treasury.amount);
+
+        if reward_pool == 0 {
+            return Ok(());
+        }
+
+        // Transfer reward pool from DAO treasury to staking vault
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.dao_treasury.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to: self.staking_vault.to_account_info(),
+                    authority: self.dao.to_account_info(),
+                },
+                &[&[
+                    b"dao",
+                    self.dao.dao_creator.to_bytes().as_ref(),
+                    self.dao.dao_seed.to_le_bytes().as_ref(),
+                    &[self.dao.bump],
+                ]],
+            ),
+            reward_pool,
+            self.dao_mint.decimals,
+        )?;
+
+        // Update proposal state
+        self.proposal.reward_pool = reward_pool;
+        self.proposal.staking_vault_balance = self
+            .proposal
+            .staking_vault_balance
+            .checked_add(reward_pool)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        self.dao.rewards_allocated = self
+            .dao
+            .rewards_allocated
+            .checked_add(reward_pool)
+            .ok_or(ErrorCode::CalculationError)?;
+        self.dao_treasury.reload()?;
+        self.dao.dao_treasury_balance = self.dao_treasury.amount;
+
+        Ok(())
+    }
+
+    // Conviction settlement takes a slice of the losing stakes for the
+    // winning side, or for the treasury when no one staked on the winning side
+    fn settle_forfeits(&mut self) -> Result<()> {
+        let forfeit_bps = self.dao.reward_config.forfeit_bps();
+        let winner = match self.proposal.winning_vote {
+            Some(winner) if forfeit_bps > 0 => winner,
+            _ => return Ok(()),
+        };
+
+        let losing_stake = self
+            .proposal
+            .losing_stake()
+            .ok_or(ErrorCode::CalculationError)?;
+        let forfeited_stake =
+            u64::try_from(losing_stake as u128 * forfeit_bps as u128 / BPS_DENOMINATOR as u128)
+                .map_err(|_| ErrorCode::CalculationError)?;
+
+        let forfeit_to_winners = self.dao.reward_config.forfeit_recipient
+            == ForfeitRecipient::WinningSide
+            && self.proposal.staked_on(winner) > 0;
+
+        self.proposal.forfeit_bps = forfeit_bps;
+        self.proposal.forfeited_stake = forfeited_stake;
+        self.proposal.forfeit_to_winners = forfeit_to_winners;
+
+        // forfeits for the winning side stay in the staking vault
+        if forfeit_to_winners || forfeited_stake == 0 {
+            return Ok(());
+        }
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.staking_vault.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to: self.dao_treasury.to_account_info(),
+                    authority: self.proposal.to_account_info(),
+                },
+                &[&[
+                    b"proposal",
+                    self.proposal.dao.as_ref(),
+                    self.proposal.proposal_owner.to_bytes().as_ref(),
+                    self.proposal.proposal_seed.to_le_bytes().as_ref(),
+                    &[self.proposal.bump],
+                ]],
+            ),
+            forfeited_stake,
+            self.dao_mint.decimals,
+        )?;
+
+        self.proposal.staking_vault_balance = self
+            .proposal
+            .staking_vault_balance
+            .checked_sub(forfeited_stake)
+            .ok_or(ErrorCode::CalculationError)?;
+        self.dao_treasury.reload()?;
+        self.dao.dao_treasury_balance = self.dao_treasury.amount;
+
+        Ok(())
+    }
+}
@@ -1,2 +1,26 @@
 pub mod proposer;
 pub use proposer::*;
+
+pub mod queue_proposal;
+pub use queue_proposal::*;
+
+pub mod finalize_proposal;
+pub use finalize_proposal::*;
+
+pub mod execute_proposal;
+pub use execute_proposal::*;
+
+pub mod cancel_proposal;
+pub use cancel_proposal::*;
+
+pub mod add_instruction;
+pub use add_instruction::*;
+
+pub mod execute_instruction;
+pub use execute_instruction::*;
+
+pub mod close_proposal;
+pub use close_proposal::*;
+
+pub mod open_voting;
+pub use open_voting::*;
@@ -0,0 +1,32 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// Accounts for opening voting on a draft proposal
+#[derive(Accounts)]
+pub struct OpenVoting<'info> {
+    // Draft proposal whose discussion period is over
+    #[account(
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    #[account(
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+}
+
+impl<'info> OpenVoting<'info> {
+    // voting power is snapshotted when voting opens, not at creation
+    pub fn open_voting(&mut self) -> Result<()> {
+        require!(!self.dao.dissolved, ErrorCode::DaoDissolved);
+
+        let clock = Clock::get()?;
+        self.proposal.open_voting(clock.unix_timestamp, clock.slot)
+    }
+}
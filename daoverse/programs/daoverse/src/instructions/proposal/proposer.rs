@@ -1,16 +1,16 @@
 use crate::errors::ErrorCode;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{Mint, TokenAccount, TokenInterface},
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
 //accounts
 #[derive(Accounts)]
 #[instruction(proposal_seed: u64)]
-
 pub struct Proposer<'info> {
     //proposer as signer
     #[account(mut)]
@@ -26,12 +26,38 @@ pub struct Proposer<'info> {
     )]
     pub proposer_dao_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    //dao the proposal belongs to
+    #[account(
+        mut,
+        has_one = dao_mint,
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    //proposer membership in the dao
+    #[account(
+        mut,
+        seeds = [b"member", dao.key().as_ref(), proposer.key().as_ref()],
+        bump = member.bump,
+        constraint = member.dao_member == proposer.key() @ ErrorCode::Unauthorized,
+        constraint = member.dao_joined == dao.key() @ ErrorCode::MemberRequired,
+    )]
+    pub member: Box<Account<'info, DaoMemberState>>,
+
+    //dao treasury the proposal cost is paid from
+    #[account(
+        associated_token::mint = dao_mint,
+        associated_token::authority = dao,
+    )]
+    pub dao_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
     //proposal state
     #[account(
         init,
         payer = proposer,
         space= 8 + ProposalState::INIT_SPACE,
-        seeds=[b"proposal", proposer.key().as_ref(), proposal_seed.to_le_bytes().as_ref()],
+        seeds=[b"proposal", dao.key().as_ref(), proposer.key().as_ref(), proposal_seed.to_le_bytes().as_ref()],
         bump,
     )]
     pub proposal: Box<Account<'info, ProposalState>>,
@@ -45,6 +71,16 @@ pub struct Proposer<'info> {
     )]
     pub staking_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    //next entry of the dao proposal registry
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + ProposalIndex::INIT_SPACE,
+        seeds = [b"proposal_index", dao.key().as_ref(), dao.total_proposals.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal_index: Box<Account<'info, ProposalIndex>>,
+
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -53,16 +89,35 @@ pub struct Proposer<'info> {
 impl<'info> Proposer<'info> {
     //validate proposer
     pub fn validate_proposer(&self) -> Result<()> {
+        require!(!self.dao.dissolved, ErrorCode::DaoDissolved);
         if self.proposer_dao_ata.mint != self.dao_mint.key() {
             return err!(ErrorCode::InvalidDaoMint);
         }
-        if self.proposer_dao_ata.amount < 200 {
+        let governance_model = &self.dao.governance_model;
+        let min_proposer_amount = to_base_units(
+            self.dao.eligibility_threshold.min_proposer_tokens,
+            self.dao_mint.decimals,
+        )
+        .ok_or(ErrorCode::CalculationError)?;
+
+        if governance_model.uses_token_balance()
+            && self.proposer_dao_ata.amount < min_proposer_amount
+        {
             return err!(ErrorCode::InsufficientDaoTokens);
         }
+
+        if governance_model.uses_reputation() {
+            let reputation = self.member.reputation_score(Clock::get()?.unix_timestamp);
+            if reputation < MIN_PROPOSER_REPUTATION {
+                return err!(ErrorCode::InsufficientReputation);
+            }
+        }
+
         Ok(())
     }
 
     //create proposal
+    #[allow(clippy::too_many_arguments)]
     pub fn create_proposal(
         &mut self,
         bumps: ProposerBumps,
@@ -70,11 +125,81 @@ impl<'info> Proposer<'info> {
         proposal_title: String,
         proposal_details: String,
         proposal_cost: u64,
+        recipient: Pubkey,
         min_token_stake: u64,
         voting_end_time: i64,
-    ) {
+        choices: Vec<String>,
+        metadata_uri: String,
+        content_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            proposal_title.len() <= MAX_PROPOSAL_TITLE_LEN,
+            ErrorCode::StringTooLong
+        );
+        require!(
+            proposal_details.len() <= MAX_PROPOSAL_DETAILS_LEN,
+            ErrorCode::StringTooLong
+        );
+        require!(
+            metadata_uri.len() <= MAX_METADATA_URI_LEN,
+            ErrorCode::StringTooLong
+        );
+        require!(
+            proposal_cost <= self.dao_treasury.amount,
+            ErrorCode::InsufficientFunds
+        );
+
+        // a dao discussion period keeps the proposal in draft until voting opens
+        let clock = Clock::get()?;
+        let discussion_period =
+            i64::try_from(self.dao.discussion_period).map_err(|_| ErrorCode::CalculationError)?;
+        let voting_start_time = clock
+            .unix_timestamp
+            .checked_add(discussion_period)
+            .ok_or(ErrorCode::CalculationError)?;
+        let status = if discussion_period > 0 {
+            ProposalStatus::Draft
+        } else {
+            ProposalStatus::Active
+        };
+        require!(
+            self.dao
+                .voting_threshold
+                .is_valid_voting_window(voting_start_time, voting_end_time),
+            ErrorCode::InvalidVotingPeriod
+        );
+
+        // no choices makes a yes/no proposal
+        require!(
+            choices.is_empty() || (2..=MAX_PROPOSAL_CHOICES).contains(&choices.len()),
+            ErrorCode::InvalidChoices
+        );
+        // the payout and instructions are not tied to a choice, so only
+        // yes/no proposals can carry them
+        require!(
+            choices.is_empty() || proposal_cost == 0,
+            ErrorCode::MultiChoicePayload
+        );
+        let choices = choices
+            .into_iter()
+            .map(|label| {
+                require!(!label.is_empty(), ErrorCode::InvalidChoices);
+                require!(
+                    label.len() <= MAX_CHOICE_LABEL_LEN,
+                    ErrorCode::StringTooLong
+                );
+                Ok(ProposalChoice {
+                    label,
+                    vote_count: 0,
+                    weighted: 0,
+                    staked: 0,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
         self.proposal.set_inner(ProposalState {
             proposal_seed,
+            dao: self.dao.key(),
             proposal_owner: self.proposer.key(),
             bump: bumps.proposal,
             proposal_title,
@@ -83,8 +208,106 @@ impl<'info> Proposer<'info> {
             min_token_stake,
             vote_count_yes: 0,
             vote_count_no: 0,
+            weighted_yes: 0,
+            weighted_no: 0,
             voting_end_time,
             staking_vault_balance: 0,
+            recipient,
+            status,
+            eta: 0,
+            execution_delay: self.dao.voting_threshold.execution_delay,
+            instruction_count: 0,
+            executed_instructions: 0,
+            vote_count_abstain: 0,
+            weighted_abstain: 0,
+            choices,
+            winning_choice: None,
+            total_reward_weight: 0,
+            reward_pool: 0,
+            finalized: false,
+            settled_at: 0,
+            staked_yes: 0,
+            staked_no: 0,
+            staked_abstain: 0,
+            winning_vote: None,
+            forfeit_bps: 0,
+            forfeited_stake: 0,
+            forfeit_to_winners: false,
+            snapshot_slot: clock.slot,
+            snapshot_time: clock.unix_timestamp,
+            open_vote_records: 0,
+            deposit: 0,
+            quorum_reached: false,
+            metadata_uri,
+            content_hash,
+            voting_start_time,
+            proposal_index: self.dao.total_proposals,
+            voting_started: false,
+            voting_threshold: self.dao.voting_threshold.clone(),
+        });
+
+        self.lock_deposit()?;
+
+        // Register the proposal under the next dao index
+        self.proposal_index.set_inner(ProposalIndex {
+            dao: self.dao.key(),
+            index: self.dao.total_proposals,
+            proposal: self.proposal.key(),
+            bump: bumps.proposal_index,
+        });
+
+        // Track proposals created by the member and the dao
+        self.member.created_proposals = self
+            .member
+            .created_proposals
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        self.dao.total_proposals = self
+            .dao
+            .total_proposals
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        emit!(ProposalCreated {
+            dao: self.dao.key(),
+            proposal: self.proposal.key(),
+            proposer: self.proposer.key(),
+            proposal_index: self.proposal_index.index,
+            status: self.proposal.status,
+            proposal_cost: self.proposal.proposal_cost,
+            recipient: self.proposal.recipient,
+            voting_start_time: self.proposal.voting_start_time,
+            voting_end_time: self.proposal.voting_end_time,
+            content_hash: self.proposal.content_hash,
         });
+
+        Ok(())
+    }
+
+    // Move the dao proposal deposit into the staking vault until finalization
+    fn lock_deposit(&mut self) -> Result<()> {
+        let deposit = to_base_units(self.dao.proposal_deposit, self.dao_mint.decimals)
+            .ok_or(ErrorCode::CalculationError)?;
+        if deposit == 0 {
+            return Ok(());
+        }
+
+        transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.proposer_dao_ata.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to: self.staking_vault.to_account_info(),
+                    authority: self.proposer.to_account_info(),
+                },
+            ),
+            deposit,
+            self.dao_mint.decimals,
+        )?;
+        self.proposal.deposit = deposit;
+
+        Ok(())
     }
 }
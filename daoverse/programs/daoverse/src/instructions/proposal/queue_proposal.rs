@@ -0,0 +1,49 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// Accounts for queueing a succeeded proposal behind the dao execution delay
+#[derive(Accounts)]
+pub struct QueueProposal<'info> {
+    // Proposal being queued
+    #[account(
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    #[account(
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+}
+
+impl<'info> QueueProposal<'info> {
+    pub fn queue_proposal(&mut self) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+
+        // Tally votes if no one has settled the proposal yet
+        if self.proposal.status == ProposalStatus::Active {
+            self.proposal.settle(self.dao.member_count, current_time)?;
+
+            // persist the defeat instead of failing the transaction
+            if self.proposal.status == ProposalStatus::Defeated {
+                return Ok(());
+            }
+        }
+
+        self.proposal.queue(current_time)?;
+
+        emit!(ProposalQueued {
+            dao: self.dao.key(),
+            proposal: self.proposal.key(),
+            eta: self.proposal.eta,
+        });
+
+        Ok(())
+    }
+}
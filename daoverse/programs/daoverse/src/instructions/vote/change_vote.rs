@@ -0,0 +1,180 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+// Accounts for changing a vote while the proposal is active
+#[derive(Accounts)]
+pub struct ChangeVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+        has_one = dao_mint,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    // voter's membership, the new stake is weighted with it
+    #[account(
+        seeds = [b"member", dao.key().as_ref(), voter.key().as_ref()],
+        bump = member.bump,
+        constraint = member.dao_member == voter.key() @ ErrorCode::Unauthorized,
+        constraint = member.dao_joined == dao.key() @ ErrorCode::MemberRequired,
+    )]
+    pub member: Box<Account<'info, DaoMemberState>>,
+
+    #[account(
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    // only votes cast by the voter themselves can be changed
+    #[account(
+        mut,
+        seeds = [b"voter", voter.key().as_ref(), proposal.key().as_ref()],
+        bump = vote_record.bump,
+        constraint = vote_record.cast_by == voter.key() @ ErrorCode::InvalidVoter,
+    )]
+    pub vote_record: Box<Account<'info, VoteState>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = voter,
+    )]
+    pub voter_dao_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = proposal,
+    )]
+    pub staking_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ChangeVote<'info> {
+    // Switch sides and/or adjust the stake, delegated power follows the vote
+    // vote weight stays fixed by the proposal snapshot, only the stake changes
+    pub fn change_vote(&mut self, vote_type: VoteType, tokens_to_stake: u64) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp;
+        self.proposal.require_open_for_voting(current_time)?;
+
+        if tokens_to_stake < self.proposal.min_token_stake {
+            return err!(ErrorCode::InsufficientStake);
+        }
+
+        let reward_weight = self
+            .dao
+            .member_reward_weight(&self.member, tokens_to_stake, current_time)
+            .ok_or(ErrorCode::CalculationError)?;
+        self.proposal.total_reward_weight = self
+            .proposal
+            .total_reward_weight
+            .checked_sub(self.vote_record.reward_weight)
+            .and_then(|total| total.checked_add(reward_weight))
+            .ok_or(ErrorCode::CalculationError)?;
+        self.vote_record.reward_weight = reward_weight;
+
+        // Move the record from the old tally to the new one
+        let (voters, weight) = self
+            .vote_record
+            .tallied_votes()
+            .ok_or(ErrorCode::CalculationError)?;
+        self.proposal
+            .remove_votes(self.vote_record.vote_type, voters, weight)?;
+        self.proposal
+            .remove_stake(self.vote_record.vote_type, self.vote_record.tokens_staked)?;
+
+        self.vote_record.vote_type = vote_type;
+
+        let (voters, weight) = self
+            .vote_record
+            .tallied_votes()
+            .ok_or(ErrorCode::CalculationError)?;
+        self.proposal.add_votes(vote_type, voters, weight)?;
+        self.proposal.add_stake(vote_type, tokens_to_stake)?;
+
+        // Settle the stake difference with the staking vault
+        let staked_amount = self.vote_record.tokens_staked;
+        if tokens_to_stake > staked_amount {
+            let top_up = tokens_to_stake - staked_amount;
+            if self.voter_dao_ata.amount < top_up {
+                return err!(ErrorCode::InsufficientDaoTokens);
+            }
+
+            transfer_checked(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.voter_dao_ata.to_account_info(),
+                        mint: self.dao_mint.to_account_info(),
+                        to: self.staking_vault.to_account_info(),
+                        authority: self.voter.to_account_info(),
+                    },
+                ),
+                top_up,
+                self.dao_mint.decimals,
+            )?;
+
+            self.proposal.staking_vault_balance = self
+                .proposal
+                .staking_vault_balance
+                .checked_add(top_up)
+                .ok_or(ErrorCode::CalculationError)?;
+        } else if tokens_to_stake < staked_amount {
+            let refund = staked_amount - tokens_to_stake;
+
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.staking_vault.to_account_info(),
+                        mint: self.dao_mint.to_account_info(),
+                        to: self.voter_dao_ata.to_account_info(),
+                        authority: self.proposal.to_account_info(),
+                    },
+                    &[&[
+                        b"proposal",
+                        self.proposal.dao.as_ref(),
+                        self.proposal.proposal_owner.to_bytes().as_ref(),
+                        self.proposal.proposal_seed.to_le_bytes().as_ref(),
+                        &[self.proposal.bump],
+                    ]],
+                ),
+                refund,
+                self.dao_mint.decimals,
+            )?;
+
+            self.proposal.staking_vault_balance = self
+                .proposal
+                .staking_vault_balance
+                .checked_sub(refund)
+                .ok_or(ErrorCode::CalculationError)?;
+        }
+
+        self.vote_record.tokens_staked = tokens_to_stake;
+
+        emit!(VoteChanged {
+            dao: self.dao.key(),
+            proposal: self.proposal.key(),
+            voter: self.voter.key(),
+            vote_type,
+            tokens_staked: tokens_to_stake,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+// Accounts for closing a paid out vote record, anyone can crank it
+#[derive(Accounts)]
+pub struct CloseVote<'info> {
+    // rent goes back to whoever paid for the record
+    #[account(
+        mut,
+        address = vote_record.cast_by @ ErrorCode::InvalidVoter,
+    )]
+    pub rent_receiver: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    #[account(
+        mut,
+        close = rent_receiver,
+        seeds = [b"voter", vote_record.voter.to_bytes().as_ref(), proposal.key().as_ref()],
+        bump = vote_record.bump,
+        constraint = vote_record.proposal_id == proposal.key() @ ErrorCode::InvalidProposal,
+    )]
+    pub vote_record: Box<Account<'info, VoteState>>,
+}
+
+impl<'info> CloseVote<'info> {
+    pub fn close_vote(&mut self) -> Result<()> {
+        require!(self.proposal.finalized, ErrorCode::ProposalNotFinalized);
+        require!(self.vote_record.is_settled(), ErrorCode::VoteNotSettled);
+
+        self.proposal.open_vote_records = self
+            .proposal
+            .open_vote_records
+            .checked_sub(1)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        Ok(())
+    }
+}
@@ -3,3 +3,12 @@ pub use voter::*;
 
 pub mod rewards;
 pub use rewards::*;
+
+pub mod change_vote;
+pub use change_vote::*;
+
+pub mod withdraw_vote;
+pub use withdraw_vote::*;
+
+pub mod close_vote;
+pub use close_vote::*;
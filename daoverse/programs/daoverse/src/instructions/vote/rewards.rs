@@ -1,4 +1,5 @@
 use crate::errors::ErrorCode;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
 
@@ -10,7 +11,7 @@ use anchor_spl::{
     },
 };
 
-// Accounts for claiming rewards after voting
+// Accounts for claiming rewards once the proposal is finalized
 #[derive(Accounts)]
 pub struct ClaimRewards<'info> {
     #[account(mut)]
@@ -20,9 +21,10 @@ pub struct ClaimRewards<'info> {
 
     // Proposal that was voted on
     #[account(
-        mut, 
-        seeds=[b"proposal", proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
         bump=proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
     )]
     pub proposal: Box<Account<'info, ProposalState>>,
 
@@ -43,30 +45,29 @@ pub struct ClaimRewards<'info> {
     pub staking_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        mut,
         seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
         bump = dao.bump,
     )]
     pub dao: Box<Account<'info, DaoConfig>>,
 
-    //dao treasury - vault
+    // voter's membership, tracks rewards earned in the dao
     #[account(
         mut,
-        associated_token::mint = dao_mint,
-        associated_token::authority = dao,
+        seeds = [b"member", dao.key().as_ref(), voter.key().as_ref()],
+        bump = member.bump,
+        constraint = member.dao_member == voter.key() @ ErrorCode::Unauthorized,
+        constraint = member.dao_joined == dao.key() @ ErrorCode::MemberRequired,
     )]
-    pub dao_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub member: Option<Box<Account<'info, DaoMemberState>>>,
 
     // Vote record
     #[account(
         mut,
         seeds = [b"voter", vote_record.voter.to_bytes().as_ref(), vote_record.proposal_id.to_bytes().as_ref()],
         bump = vote_record.bump,
-
-        //
-        // constraint = vote_record.voter == voter.key() @ ErrorCode::InvalidVoter,
-        // constraint = vote_record.proposal_id == proposal.key() @ ErrorCode::InvalidProposal,
-        // constraint = !vote_record.claimed @ ErrorCode::RewardsAlreadyClaimed,
+        constraint = vote_record.voter == voter.key() @ ErrorCode::InvalidVoter,
+        constraint = vote_record.proposal_id == proposal.key() @ ErrorCode::InvalidProposal,
+        constraint = !vote_record.claimed @ ErrorCode::RewardsAlreadyClaimed,
     )]
     pub vote_record: Box<Account<'info, VoteState>>,
 
@@ -76,62 +77,66 @@ pub struct ClaimRewards<'info> {
 }
 
 impl<'info> ClaimRewards<'info> {
-    // Finalize proposal when voting ends
-
-
-    pub fn finalize_proposal(&mut self) -> Result<()> {
-        // Check if voting period has ended
-        let _current_time = Clock::get()?.unix_timestamp;
-        // if current_time < self.proposal.voting_end_time {
-        //     return err!(ErrorCode::VotingPeriodNotEnded);
-        // }
-
-        // Calculate 20% reward from total staked tokens
-        let reward_amount = self
-            .proposal
-            .staking_vault_balance
-            .checked_mul(20)
-            .ok_or(ErrorCode::CalculationError)?
-            .checked_div(100)
+    // Claim staked tokens plus the vested part of the voter's reward
+    // claims only read the snapshot taken by finalize_proposal
+    pub fn claim_rewards(&mut self) -> Result<()> {
+        require!(self.proposal.finalized, ErrorCode::ProposalNotFinalized);
+
+        // Voter's share of the reward pool under the dao reward model
+        let reward_share = if self.proposal.total_reward_weight == 0 {
+            0
+        } else {
+            u64::try_from(
+                self.proposal.reward_pool as u128 * self.vote_record.reward_weight as u128
+                    / self.proposal.total_reward_weight as u128,
+            )
+            .map_err(|_| ErrorCode::CalculationError)?
+        };
+
+        let vested = self
+            .dao
+            .reward_config
+            .vested_amount(
+                &self.dao.reward_model,
+                reward_share,
+                self.proposal.settled_at,
+                Clock::get()?.unix_timestamp,
+            )
+            .ok_or(ErrorCode::CalculationError)?;
+        let reward = vested
+            .checked_sub(self.vote_record.rewards_claimed)
             .ok_or(ErrorCode::CalculationError)?;
 
-        // Transfer reward from DAO treasury to staking vault
-        transfer_checked(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                TransferChecked {
-                    from: self.dao_treasury.to_account_info(),
-                    mint: self.dao_mint.to_account_info(),
-                    to: self.staking_vault.to_account_info(),
-                    authority: self.dao.to_account_info(),
-                },
-                &[&[
-                    b"dao",
-                    self.dao.dao_creator.to_bytes().as_ref(),
-                    self.dao.dao_seed.to_le_bytes().as_ref(),
-                    &[self.dao.bump],
-                ]],
-            ),
-            reward_amount,
-            self.dao_mint.decimals,
-        )?;
-        // Update proposal state
-        self.proposal.staking_vault_balance = self
-            .proposal
-            .staking_vault_balance
-            .checked_add(reward_amount)
+        // the stake is returned with the first claim, less any forfeit
+        // and plus the share of forfeits for the winning side
+        let (staked_amount, forfeit_share) = if self.vote_record.stake_returned {
+            (0, 0)
+        } else {
+            let forfeit = self
+                .proposal
+                .stake_forfeit(&self.vote_record)
+                .ok_or(ErrorCode::CalculationError)?;
+            let staked_amount = self
+                .vote_record
+                .tokens_staked
+                .checked_sub(forfeit)
+                .ok_or(ErrorCode::CalculationError)?;
+            let forfeit_share = self
+                .proposal
+                .forfeit_share(&self.vote_record)
+                .ok_or(ErrorCode::CalculationError)?;
+            (staked_amount, forfeit_share)
+        };
+        let earned = reward
+            .checked_add(forfeit_share)
             .ok_or(ErrorCode::CalculationError)?;
-        self.proposal.voting_end_time = 0; // Mark as finalized
 
-        Ok(())
-    }
-    // Claim staked tokens plus interest
+        let payout = staked_amount
+            .checked_add(earned)
+            .ok_or(ErrorCode::CalculationError)?;
+        require!(payout > 0, ErrorCode::NothingToClaim);
 
-    pub fn claim_rewards(&mut self) -> Result<()> {
-        // Calculate reward based on staked amount
-        let staked_amount = self.vote_record.tokens_staked;
-        
-        // Transfer original staked amount from staking vault to voter
+        // Transfer stake and reward from staking vault to voter
         transfer_checked(
             CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
@@ -143,59 +148,46 @@ impl<'info> ClaimRewards<'info> {
                 },
                 &[&[
                     b"proposal",
+                    self.proposal.dao.as_ref(),
                     self.proposal.proposal_owner.to_bytes().as_ref(),
                     self.proposal.proposal_seed.to_le_bytes().as_ref(),
                     &[self.proposal.bump],
                 ]],
             ),
-            staked_amount,
+            payout,
             self.dao_mint.decimals,
         )?;
-    
-        // Calculate interest (20% of staked amount)
-        let interest = staked_amount
-            .checked_mul(20)
-            .ok_or(ErrorCode::CalculationError)?
-            .checked_div(100)
-            .ok_or(ErrorCode::CalculationError)?;
-    
-        // Check if dao treasury has enough for interest
-        if self.dao_treasury.amount < interest {
-            return err!(ErrorCode::InsufficientFunds);
+
+        // Track rewards earned by the member
+        if let Some(member) = self.member.as_mut() {
+            member.total_rewards = member
+                .total_rewards
+                .checked_add(earned)
+                .ok_or(ErrorCode::Overflow)?;
         }
-    
-        // Transfer interest from treasury to voter
-        transfer_checked(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                TransferChecked {
-                    from: self.dao_treasury.to_account_info(),
-                    mint: self.dao_mint.to_account_info(),
-                    to: self.voter_dao_ata.to_account_info(),
-                    authority: self.dao.to_account_info(),
-                },
-                &[&[
-                    b"dao",
-                    self.dao.dao_creator.to_bytes().as_ref(),
-                    self.dao.dao_seed.to_le_bytes().as_ref(),
-                    &[self.dao.bump],
-                ]],
-            ),
-            interest,
-            self.dao_mint.decimals,
-        )?;
-    
+
         // Update proposal staking vault balance after withdrawal
         self.proposal.staking_vault_balance = self
             .proposal
             .staking_vault_balance
-            .checked_sub(staked_amount)
+            .checked_sub(payout)
             .ok_or(ErrorCode::CalculationError)?;
-    
-        // Mark vote as claimed
-        self.vote_record.claimed = true;
-    
+
+        // Vote is fully claimed once the whole reward has vested
+        self.vote_record.stake_returned = true;
+        self.vote_record.rewards_claimed = vested;
+        self.vote_record.claimed = vested == reward_share;
+
+        emit!(RewardsClaimed {
+            dao: self.dao.key(),
+            proposal: self.proposal.key(),
+            voter: self.vote_record.voter,
+            stake_returned: staked_amount,
+            rewards: earned,
+        });
+
         // If staking vault is now empty, close it
+        self.staking_vault.reload()?;
         if self.staking_vault.amount == 0 {
             close_account(CpiContext::new_with_signer(
                 self.token_program.to_account_info(),
@@ -206,13 +198,14 @@ impl<'info> ClaimRewards<'info> {
                 },
                 &[&[
                     b"proposal",
+                    self.proposal.dao.as_ref(),
                     self.proposal.proposal_owner.to_bytes().as_ref(),
                     self.proposal.proposal_seed.to_le_bytes().as_ref(),
                     &[self.proposal.bump],
                 ]],
             ))?;
         }
-    
+
         Ok(())
     }
 }
@@ -1,6 +1,10 @@
 use crate::errors::ErrorCode;
+use crate::events::*;
 use crate::state::*;
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
 
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -10,8 +14,6 @@ use anchor_spl::{
 // Accounts for voting on a proposal
 #[derive(Accounts)]
 #[instruction(vote_seed: u64)]
-
-
 pub struct Voter<'info> {
     #[account(mut)]
     pub voter: Signer<'info>,
@@ -29,7 +31,6 @@ pub struct Voter<'info> {
     pub vote_recordss: Box<Account<'info, VoteState>>,
 
     // voter.key().as_ref(),proposal.key().as_ref(), vote_seed.to_le_bytes().as_ref()
-
     #[account(
         mut,
         seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
@@ -37,11 +38,29 @@ pub struct Voter<'info> {
     )]
     pub dao: Box<Account<'info, DaoConfig>>,
 
+    // voter's membership in the dao
+    #[account(
+        mut,
+        seeds = [b"member", dao.key().as_ref(), voter.key().as_ref()],
+        bump = member.bump,
+        constraint = member.dao_member == voter.key() @ ErrorCode::Unauthorized,
+        constraint = member.dao_joined == dao.key() @ ErrorCode::MemberRequired,
+    )]
+    pub member: Box<Account<'info, DaoMemberState>>,
+
+    // voter's governance deposit, voting power is its balance at the proposal snapshot
+    #[account(
+        seeds = [b"governance", dao.key().as_ref(), voter.key().as_ref()],
+        bump = governance_deposit.bump,
+    )]
+    pub governance_deposit: Option<Box<Account<'info, GovernanceDepositState>>>,
+
     // Proposal being voted on
     #[account(
-        mut, 
-        seeds=[b"proposal", proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
         bump = proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
     )]
     pub proposal: Box<Account<'info, ProposalState>>,
 
@@ -61,8 +80,6 @@ pub struct Voter<'info> {
     )]
     pub staking_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    
-
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -71,14 +88,17 @@ pub struct Voter<'info> {
 impl<'info> Voter<'info> {
     // Validate the voter has enough tokens and meets requirements
     pub fn validate_voter(&self, tokens_to_stake: u64) -> Result<()> {
-        // Check if voting period has ended
-        let current_time = Clock::get()?.unix_timestamp;
-        if current_time >= self.proposal.voting_end_time || self.proposal.voting_end_time == 0 {
-            return err!(ErrorCode::VotingPeriodEnded);
-        }
+        // Only active proposals accept votes until the voting period ends
+        self.proposal
+            .require_open_for_voting(Clock::get()?.unix_timestamp)?;
 
-        // Check if voter has minimum required tokens (150)
-        if self.voter_dao_ata.amount < 150 {
+        // Check if voter had the dao's minimum voter balance at the snapshot
+        let min_voter_amount = to_base_units(
+            self.dao.eligibility_threshold.min_voter_tokens,
+            self.dao_mint.decimals,
+        )
+        .ok_or(ErrorCode::CalculationError)?;
+        if self.voting_power()? < min_voter_amount {
             return err!(ErrorCode::InsufficientDaoTokens);
         }
 
@@ -95,6 +115,46 @@ impl<'info> Voter<'info> {
         Ok(())
     }
 
+    // Voting power of the voter at the proposal snapshot
+    fn voting_power(&self) -> Result<u64> {
+        self.snapshot_power(
+            &self.member,
+            self.governance_deposit.as_deref().map(|deposit| &**deposit),
+        )
+    }
+
+    // Deposited balance plus the escrow lock power at the proposal snapshot
+    fn snapshot_power(
+        &self,
+        member: &DaoMemberState,
+        deposit: Option<&GovernanceDepositState>,
+    ) -> Result<u64> {
+        let deposited =
+            deposit.map_or(0, |deposit| deposit.balance_at(self.proposal.snapshot_slot));
+        let escrowed = member
+            .escrow_power_at(self.proposal.snapshot_slot, self.proposal.snapshot_time)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        Ok(deposited
+            .checked_add(escrowed)
+            .ok_or(ErrorCode::CalculationError)?)
+    }
+
+    // Vote weight of a member for the given token amount
+    fn member_vote_weight(&self, member: &DaoMemberState, tokens: u64) -> Result<u64> {
+        let vote_weight = self
+            .dao
+            .member_vote_weight(
+                member,
+                tokens,
+                self.dao_mint.decimals,
+                Clock::get()?.unix_timestamp,
+            )
+            .ok_or(ErrorCode::CalculationError)?;
+
+        Ok(vote_weight)
+    }
+
     // Record vote and transfer tokens to staking vault
     pub fn cast_vote(
         &mut self,
@@ -103,6 +163,16 @@ impl<'info> Voter<'info> {
         tokens_to_stake: u64,
         vote_seed: u64,
     ) -> Result<()> {
+        let vote_weight = self.member_vote_weight(&self.member, self.voting_power()?)?;
+        if vote_weight == 0 {
+            return err!(ErrorCode::InsufficientStake);
+        }
+
+        let reward_weight = self
+            .dao
+            .member_reward_weight(&self.member, tokens_to_stake, Clock::get()?.unix_timestamp)
+            .ok_or(ErrorCode::CalculationError)?;
+
         // Record vote in the vote record account
         self.vote_recordss.set_inner(VoteState {
             voter: self.voter.key(),
@@ -111,26 +181,36 @@ impl<'info> Voter<'info> {
             bump: bumps.vote_recordss,
             vote_type,
             tokens_staked: tokens_to_stake,
+            vote_weight,
             claimed: false,
+            cast_by: self.voter.key(),
+            delegated_weight: 0,
+            delegated_votes: 0,
+            reward_weight,
+            rewards_claimed: 0,
+            stake_returned: false,
         });
 
-        // Update proposal vote counts
-        match vote_type {
-            VoteType::Yes => {
-                self.proposal.vote_count_yes = self
-                    .proposal
-                    .vote_count_yes
-                    .checked_add(1)
-                    .ok_or(ErrorCode::CalculationError)?;
-            }
-            VoteType::No => {
-                self.proposal.vote_count_no = self
-                    .proposal
-                    .vote_count_no
-                    .checked_add(1)
-                    .ok_or(ErrorCode::CalculationError)?;
-            }
-        }
+        // Update proposal vote counts and weighted tallies
+        self.proposal.add_votes(vote_type, 1, vote_weight)?;
+        self.proposal.add_stake(vote_type, tokens_to_stake)?;
+        self.proposal.total_reward_weight = self
+            .proposal
+            .total_reward_weight
+            .checked_add(reward_weight)
+            .ok_or(ErrorCode::CalculationError)?;
+        self.proposal.open_vote_records = self
+            .proposal
+            .open_vote_records
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+
+        // Track votes cast by the member
+        self.member.total_votes = self
+            .member
+            .total_votes
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
 
         // Update staking vault balance
         self.proposal.staking_vault_balance = self
@@ -156,4 +236,195 @@ impl<'info> Voter<'info> {
 
         Ok(())
     }
+
+    // Add the voting power delegated to the voter
+    // remaining accounts come in groups of four per delegator:
+    // delegation, delegator member, delegator governance deposit, delegator vote record (uninitialized)
+    // the delegator vote record is created so the delegator cannot vote on the proposal again
+    pub fn cast_delegated_votes(
+        &mut self,
+        delegations: &'info [AccountInfo<'info>],
+        vote_type: VoteType,
+        vote_seed: u64,
+    ) -> Result<()> {
+        let delegators = delegations.chunks_exact(DELEGATION_ACCOUNTS_LEN);
+        require!(
+            delegators.remainder().is_empty(),
+            ErrorCode::InvalidDelegation
+        );
+
+        let proposal_key = self.proposal.key();
+        let mut delegated_weight: u64 = 0;
+        let mut delegated_votes: u64 = 0;
+
+        for accounts in delegators {
+            let delegation = Account::<DelegationState>::try_from(&accounts[0])?;
+            let delegator_member = Account::<DaoMemberState>::try_from(&accounts[1])?;
+            // the program id stands in for a delegator without a deposit
+            let delegator_deposit = if accounts[2].key() == crate::ID {
+                None
+            } else {
+                Some(Account::<GovernanceDepositState>::try_from(&accounts[2])?)
+            };
+            let delegator_vote_record = &accounts[3];
+
+            let delegator = delegation.delegator;
+
+            // Delegation must point at this voter and cover this dao
+            require_keys_eq!(
+                delegation.delegate,
+                self.voter.key(),
+                ErrorCode::InvalidDelegation
+            );
+            require!(
+                delegation.covers_dao(&self.dao.key()),
+                ErrorCode::InvalidDelegation
+            );
+
+            // Delegated power only comes from members of the dao
+            require_keys_eq!(
+                delegator_member.dao_member,
+                delegator,
+                ErrorCode::InvalidDelegation
+            );
+            require_keys_eq!(
+                delegator_member.dao_joined,
+                self.dao.key(),
+                ErrorCode::MemberRequired
+            );
+
+            if let Some(deposit) = delegator_deposit.as_ref() {
+                require_keys_eq!(deposit.owner, delegator, ErrorCode::InvalidDelegation);
+                require_keys_eq!(deposit.dao, self.dao.key(), ErrorCode::InvalidDelegation);
+            }
+
+            // delegated power is also read at the proposal snapshot
+            let power = self.snapshot_power(&delegator_member, delegator_deposit.as_deref())?;
+            let weight = self.member_vote_weight(&delegator_member, power)?;
+
+            // The delegator's vote record is the same pda a direct vote would use
+            let (expected_vote_record, bump) = Pubkey::find_program_address(
+                &[b"voter", delegator.as_ref(), proposal_key.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                delegator_vote_record.key(),
+                expected_vote_record,
+                ErrorCode::InvalidDelegation
+            );
+            require!(
+                delegator_vote_record.data_is_empty(),
+                ErrorCode::AlreadyVoted
+            );
+
+            let space = 8 + VoteState::INIT_SPACE;
+            let rent = Rent::get()?.minimum_balance(space);
+            let record_seeds: &[&[u8]] =
+                &[b"voter", delegator.as_ref(), proposal_key.as_ref(), &[bump]];
+            let current_lamports = delegator_vote_record.lamports();
+            if current_lamports == 0 {
+                create_account(
+                    CpiContext::new_with_signer(
+                        self.system_program.to_account_info(),
+                        CreateAccount {
+                            from: self.voter.to_account_info(),
+                            to: delegator_vote_record.clone(),
+                        },
+                        &[record_seeds],
+                    ),
+                    rent,
+                    space as u64,
+                    &crate::ID,
+                )?;
+            } else {
+                // the pda was pre-funded, create_account would fail so top it up instead
+                let top_up = rent.saturating_sub(current_lamports);
+                if top_up > 0 {
+                    transfer(
+                        CpiContext::new(
+                            self.system_program.to_account_info(),
+                            Transfer {
+                                from: self.voter.to_account_info(),
+                                to: delegator_vote_record.clone(),
+                            },
+                        ),
+                        top_up,
+                    )?;
+                }
+                allocate(
+                    CpiContext::new_with_signer(
+                        self.system_program.to_account_info(),
+                        Allocate {
+                            account_to_allocate: delegator_vote_record.clone(),
+                        },
+                        &[record_seeds],
+                    ),
+                    space as u64,
+                )?;
+                assign(
+                    CpiContext::new_with_signer(
+                        self.system_program.to_account_info(),
+                        Assign {
+                            account_to_assign: delegator_vote_record.clone(),
+                        },
+                        &[record_seeds],
+                    ),
+                    &crate::ID,
+                )?;
+            }
+
+            // nothing is staked for the delegator, so there is nothing to claim
+            let record = VoteState {
+                voter: delegator,
+                proposal_id: proposal_key,
+                vote_seed,
+                bump,
+                vote_type,
+                tokens_staked: 0,
+                vote_weight: weight,
+                claimed: true,
+                cast_by: self.voter.key(),
+                delegated_weight: 0,
+                delegated_votes: 0,
+                reward_weight: 0,
+                rewards_claimed: 0,
+                stake_returned: true,
+            };
+            record.try_serialize(&mut &mut delegator_vote_record.try_borrow_mut_data()?[..])?;
+
+            delegated_weight = delegated_weight
+                .checked_add(weight)
+                .ok_or(ErrorCode::CalculationError)?;
+            delegated_votes = delegated_votes
+                .checked_add(1)
+                .ok_or(ErrorCode::CalculationError)?;
+        }
+
+        if delegated_votes > 0 {
+            self.proposal
+                .add_votes(vote_type, delegated_votes, delegated_weight)?;
+            self.proposal.open_vote_records = self
+                .proposal
+                .open_vote_records
+                .checked_add(delegated_votes)
+                .ok_or(ErrorCode::Overflow)?;
+
+            self.vote_recordss.delegated_weight = delegated_weight;
+            self.vote_recordss.delegated_votes = delegated_votes;
+        }
+
+        // the vote is complete once delegated power is added
+        emit!(VoteCast {
+            dao: self.dao.key(),
+            proposal: proposal_key,
+            voter: self.voter.key(),
+            vote_type,
+            tokens_staked: self.vote_recordss.tokens_staked,
+            vote_weight: self.vote_recordss.vote_weight,
+            delegated_weight,
+            delegated_votes,
+        });
+
+        Ok(())
+    }
 }
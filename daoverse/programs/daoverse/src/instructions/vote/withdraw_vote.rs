@@ -0,0 +1,171 @@
+use crate::errors::ErrorCode;
+use crate::events::*;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+// Accounts for withdrawing a vote while the proposal is active
+#[derive(Accounts)]
+pub struct WithdrawVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub dao_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [b"dao", dao.dao_creator.to_bytes().as_ref(), dao.dao_seed.to_le_bytes().as_ref()],
+        bump = dao.bump,
+        has_one = dao_mint,
+    )]
+    pub dao: Box<Account<'info, DaoConfig>>,
+
+    /// CHECK: the voter's member pda, withdrawn votes no longer count towards
+    /// their participation. It is only left empty once the voter has left the dao
+    #[account(
+        mut,
+        seeds = [b"member", dao.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub member: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds=[b"proposal", proposal.dao.as_ref(), proposal.proposal_owner.to_bytes().as_ref(), proposal.proposal_seed.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = dao @ ErrorCode::InvalidProposal,
+    )]
+    pub proposal: Box<Account<'info, ProposalState>>,
+
+    // closing the record lets the voter vote again
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"voter", voter.key().as_ref(), proposal.key().as_ref()],
+        bump = vote_record.bump,
+        constraint = vote_record.cast_by == voter.key() @ ErrorCode::InvalidVoter,
+    )]
+    pub vote_record: Box<Account<'info, VoteState>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = voter,
+    )]
+    pub voter_dao_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = dao_mint,
+        associated_token::authority = proposal,
+    )]
+    pub staking_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawVote<'info> {
+    // remaining accounts are the vote records of the delegators counted with this vote
+    // they are closed too so the delegators can vote on their own
+    pub fn withdraw_vote(&mut self, delegator_records: &'info [AccountInfo<'info>]) -> Result<()> {
+        self.proposal
+            .require_open_for_voting(Clock::get()?.unix_timestamp)?;
+
+        require!(
+            delegator_records.len() as u64 == self.vote_record.delegated_votes,
+            ErrorCode::InvalidDelegation
+        );
+
+        let proposal_key = self.proposal.key();
+        for record_info in delegator_records {
+            let record = Account::<VoteState>::try_from(record_info)?;
+            require_keys_eq!(
+                record.proposal_id,
+                proposal_key,
+                ErrorCode::InvalidDelegation
+            );
+            require_keys_eq!(
+                record.cast_by,
+                self.voter.key(),
+                ErrorCode::InvalidDelegation
+            );
+            require_keys_neq!(record.voter, self.voter.key(), ErrorCode::InvalidDelegation);
+
+            // the voter paid the rent of the delegator records
+            record.close(self.voter.to_account_info())?;
+        }
+
+        let (voters, weight) = self
+            .vote_record
+            .tallied_votes()
+            .ok_or(ErrorCode::CalculationError)?;
+        self.proposal
+            .remove_votes(self.vote_record.vote_type, voters, weight)?;
+        self.proposal
+            .remove_stake(self.vote_record.vote_type, self.vote_record.tokens_staked)?;
+        // the record and the delegator records are all closed
+        self.proposal.open_vote_records = self
+            .proposal
+            .open_vote_records
+            .checked_sub(voters)
+            .ok_or(ErrorCode::CalculationError)?;
+        self.proposal.total_reward_weight = self
+            .proposal
+            .total_reward_weight
+            .checked_sub(self.vote_record.reward_weight)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        // a closed member pda proves the voter left, there are no stats to update
+        if !self.member.data_is_empty() {
+            let mut member =
+                DaoMemberState::try_deserialize(&mut &self.member.try_borrow_data()?[..])?;
+            member.total_votes = member
+                .total_votes
+                .checked_sub(1)
+                .ok_or(ErrorCode::CalculationError)?;
+            member.try_serialize(&mut &mut self.member.try_borrow_mut_data()?[..])?;
+        }
+
+        // Return the stake to the voter
+        let staked_amount = self.vote_record.tokens_staked;
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.staking_vault.to_account_info(),
+                    mint: self.dao_mint.to_account_info(),
+                    to: self.voter_dao_ata.to_account_info(),
+                    authority: self.proposal.to_account_info(),
+                },
+                &[&[
+                    b"proposal",
+                    self.proposal.dao.as_ref(),
+                    self.proposal.proposal_owner.to_bytes().as_ref(),
+                    self.proposal.proposal_seed.to_le_bytes().as_ref(),
+                    &[self.proposal.bump],
+                ]],
+            ),
+            staked_amount,
+            self.dao_mint.decimals,
+        )?;
+
+        self.proposal.staking_vault_balance = self
+            .proposal
+            .staking_vault_balance
+            .checked_sub(staked_amount)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        emit!(VoteWithdrawn {
+            dao: self.dao.key(),
+            proposal: self.proposal.key(),
+            voter: self.voter.key(),
+            vote_type: self.vote_record.vote_type,
+            stake_returned: staked_amount,
+            delegated_votes: self.vote_record.delegated_votes,
+        });
+
+        Ok(())
+    }
+}
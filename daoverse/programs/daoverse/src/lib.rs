@@ -1,3 +1,5 @@
+#![allow(unexpected_cfgs)]
+
 use anchor_lang::prelude::*;
 
 declare_id!("2DCZ8tfm5Jj4GVLyGVqygQYGsZSxrWUMYdrZT8KJ1Ad4");
@@ -10,6 +12,8 @@ pub use instructions::*;
 pub mod errors;
 pub use errors::ErrorCode;
 
+pub mod events;
+
 #[program]
 pub mod daoverse {
     use super::*;
@@ -18,6 +22,7 @@ pub mod daoverse {
     pub fn initialize_daoverse(
         ctx: Context<InitializeDaoverse>,
         dao_creation_fee: u64,
+        min_creator_tokens: u64,
         admin_name: String,
         daoverse_description: String,
         amount: u64,
@@ -25,6 +30,7 @@ pub mod daoverse {
         ctx.accounts.initialize_daoverse(
             ctx.bumps,
             dao_creation_fee,
+            min_creator_tokens,
             admin_name,
             daoverse_description,
         );
@@ -36,16 +42,50 @@ pub mod daoverse {
     pub fn update_daoverse(
         ctx: Context<UpdateDaoverse>,
         dao_creation_fee: Option<u64>,
+        min_creator_tokens: Option<u64>,
         admin_name: Option<String>,
         daoverse_description: Option<String>,
+        fee_beneficiary: Option<Pubkey>,
+        fee_split_bps: Option<u16>,
     ) -> Result<()> {
-        ctx.accounts
-            .update_daoverse(dao_creation_fee, admin_name, daoverse_description)?;
+        ctx.accounts.update_daoverse(
+            dao_creation_fee,
+            min_creator_tokens,
+            admin_name,
+            daoverse_description,
+            fee_beneficiary,
+            fee_split_bps,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn withdraw_daoverse_treasury(
+        ctx: Context<WithdrawDaoverseTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        // Admin withdraws collected fees
+        ctx.accounts.withdraw_daoverse_treasury(amount)?;
+
+        Ok(())
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+        // First step of an admin transfer, None cancels a pending transfer
+        ctx.accounts.propose_admin(new_admin)?;
+
+        Ok(())
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        // The proposed admin takes over
+        ctx.accounts.accept_admin()?;
 
         Ok(())
     }
 
     //dao
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_dao(
         ctx: Context<CreateDao>,
         dao_seed: u64,
@@ -56,6 +96,8 @@ pub mod daoverse {
         voting_model: dao_config::VotingModel,
         reward_model: dao_config::RewardModel,
         voting_threshold: dao_config::VotingThreshold,
+        eligibility_threshold: dao_config::EligibilityThreshold,
+        reward_config: dao_config::RewardConfig,
     ) -> Result<()> {
         // Validate creator has sufficient tokens
         ctx.accounts.validate_creator()?;
@@ -73,7 +115,9 @@ pub mod daoverse {
             voting_model,
             reward_model,
             voting_threshold,
-        );
+            eligibility_threshold,
+            reward_config,
+        )?;
 
         //Dao creator deposit
         ctx.accounts.dao_creator_deposit(amount)?;
@@ -81,6 +125,7 @@ pub mod daoverse {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_dao(
         ctx: Context<UpdateDao>,
         creator_name: Option<String>,
@@ -89,6 +134,11 @@ pub mod daoverse {
         voting_model: Option<dao_config::VotingModel>,
         reward_model: Option<dao_config::RewardModel>,
         voting_threshold: Option<dao_config::VotingThreshold>,
+        eligibility_threshold: Option<dao_config::EligibilityThreshold>,
+        guardian: Option<Pubkey>,
+        reward_config: Option<dao_config::RewardConfig>,
+        proposal_deposit: Option<u64>,
+        discussion_period: Option<u64>,
     ) -> Result<()> {
         ctx.accounts.update_dao(
             creator_name,
@@ -97,18 +147,119 @@ pub mod daoverse {
             voting_model,
             reward_model,
             voting_threshold,
+            eligibility_threshold,
+            guardian,
+            reward_config,
+            proposal_deposit,
+            discussion_period,
         )?;
 
         Ok(())
     }
 
+    //dao roles
+    pub fn init_roles(ctx: Context<InitRoles>) -> Result<()> {
+        ctx.accounts.init_roles(ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn grant_role(
+        ctx: Context<UpdateRoles>,
+        holder: Pubkey,
+        role: DaoRole,
+        spending_limit: u64,
+        spending_period: u64,
+    ) -> Result<()> {
+        // Creator before the first proposal, governance afterwards
+        ctx.accounts
+            .grant_role(holder, role, spending_limit, spending_period)?;
+
+        Ok(())
+    }
+
+    pub fn revoke_role(ctx: Context<UpdateRoles>, holder: Pubkey, role: DaoRole) -> Result<()> {
+        ctx.accounts.revoke_role(holder, role)?;
+
+        Ok(())
+    }
+
+    pub fn treasurer_withdraw(ctx: Context<TreasurerWithdraw>, amount: u64) -> Result<()> {
+        ctx.accounts.treasurer_withdraw(amount)?;
+
+        Ok(())
+    }
+
+    //dao treasury
+    pub fn deposit_to_treasury(ctx: Context<DepositToTreasury>, amount: u64) -> Result<()> {
+        // Anyone can fund the dao treasury for the given mint
+        ctx.accounts.deposit_to_treasury(amount)?;
+
+        Ok(())
+    }
+
+    pub fn deposit_sol_to_treasury(
+        ctx: Context<DepositSolToTreasury>,
+        lamports: u64,
+    ) -> Result<()> {
+        ctx.accounts.deposit_sol_to_treasury(lamports)?;
+
+        Ok(())
+    }
+
+    pub fn sync_treasury(ctx: Context<SyncTreasury>) -> Result<()> {
+        // Reconcile the tracked balance with the treasury ata
+        ctx.accounts.sync_treasury()?;
+
+        Ok(())
+    }
+
+    pub fn dissolve_dao(ctx: Context<DissolveDao>, recipient: Pubkey) -> Result<()> {
+        // Only callable by the dao pda through an executed proposal instruction
+        ctx.accounts.dissolve_dao(recipient)?;
+
+        Ok(())
+    }
+
+    pub fn claim_dissolution(ctx: Context<ClaimDissolution>) -> Result<()> {
+        // Depositors take their share of a dissolved dao's treasury
+        ctx.accounts.claim_dissolution(ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn sweep_dissolution(ctx: Context<SweepDissolution>) -> Result<()> {
+        // Hand what depositors cannot claim to the dissolution recipient
+        ctx.accounts.sweep_dissolution(ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn withdraw_from_treasury(ctx: Context<WithdrawFromTreasury>, amount: u64) -> Result<()> {
+        // Only callable by the dao pda through an executed proposal instruction
+        ctx.accounts.withdraw_from_treasury(amount)?;
+
+        Ok(())
+    }
+
+    pub fn withdraw_sol_from_treasury(
+        ctx: Context<WithdrawSolFromTreasury>,
+        lamports: u64,
+    ) -> Result<()> {
+        // Only callable by the dao pda through an executed proposal instruction
+        ctx.accounts
+            .withdraw_sol_from_treasury(ctx.bumps, lamports)?;
+
+        Ok(())
+    }
+
     //dao member
-    pub fn initialize_member(ctx: Context<InitializeMember>, member_seed: u64) -> Result<()> {
+    pub fn initialize_member(ctx: Context<InitializeMember>) -> Result<()> {
         // Validate member has sufficient tokens
         ctx.accounts.validate_member()?;
 
         // Initialize member state
-        ctx.accounts.initialize_member(ctx.bumps, member_seed)?;
+        ctx.accounts.initialize_member(ctx.bumps)?;
 
         // Set initial balance
         ctx.accounts.update_member_balance()?;
@@ -118,30 +269,64 @@ pub mod daoverse {
 
     pub fn update_member(
         ctx: Context<UpdateMember>,
-        created_proposals: Option<u64>,
-        approved_proposals: Option<u64>,
-        total_rewards: Option<u64>,
-        total_votes: Option<u64>,
-    ) -> Result<()> {
-        ctx.accounts.update_member(
-            created_proposals,
-            approved_proposals,
-            total_rewards,
-            total_votes,
-        )?;
+        member_name: Option<String>,
+        member_description: Option<String>,
+    ) -> Result<()> {
+        ctx.accounts
+            .update_member(member_name, member_description)?;
+
+        Ok(())
+    }
+
+    pub fn leave_dao(ctx: Context<LeaveDao>) -> Result<()> {
+        // Close the membership and return its rent
+        ctx.accounts.leave_dao()?;
+
+        Ok(())
+    }
+
+    pub fn deposit_governance(ctx: Context<DepositGovernance>, amount: u64) -> Result<()> {
+        // Lock tokens as voting power, checkpointed at the current slot
+        ctx.accounts.deposit_governance(ctx.bumps, amount)?;
+
+        Ok(())
+    }
+
+    pub fn withdraw_governance(ctx: Context<WithdrawGovernance>, amount: u64) -> Result<()> {
+        // Release deposited tokens, earlier snapshots keep their balance
+        ctx.accounts.withdraw_governance(amount)?;
+
+        Ok(())
+    }
+
+    pub fn lock_tokens(ctx: Context<LockTokens>, amount: u64, lock_duration: i64) -> Result<()> {
+        // Lock tokens in the vote-escrow for time-weighted voting power
+        ctx.accounts.lock_tokens(amount, lock_duration)?;
+
+        Ok(())
+    }
+
+    pub fn unlock_tokens(ctx: Context<UnlockTokens>) -> Result<()> {
+        // Return the locked tokens once the lock has expired
+        ctx.accounts.unlock_tokens()?;
 
         Ok(())
     }
 
     //proposer
+    #[allow(clippy::too_many_arguments)]
     pub fn proposal(
         ctx: Context<Proposer>,
         proposal_seed: u64,
         proposal_title: String,
         proposal_details: String,
         proposal_cost: u64,
+        recipient: Pubkey,
         min_token_stake: u64,
         voting_end_time: i64,
+        choices: Vec<String>,
+        metadata_uri: String,
+        content_hash: [u8; 32],
     ) -> Result<()> {
         // Validate creator has sufficient tokens
         ctx.accounts.validate_proposer()?;
@@ -153,18 +338,71 @@ pub mod daoverse {
             proposal_title,
             proposal_details,
             proposal_cost,
+            recipient,
             min_token_stake,
             voting_end_time,
-        );
+            choices,
+            metadata_uri,
+            content_hash,
+        )?;
 
         // ctx.accounts.finalize_proposal()?;
 
         Ok(())
     }
 
+    pub fn open_proposal_voting(ctx: Context<OpenVoting>) -> Result<()> {
+        // Anyone can open voting once the discussion period is over
+        ctx.accounts.open_voting()?;
+
+        Ok(())
+    }
+
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        ctx.accounts.cancel_proposal()?;
+
+        Ok(())
+    }
+
+    pub fn queue_proposal(ctx: Context<QueueProposal>) -> Result<()> {
+        // Tally votes and start the execution delay if it passed
+        ctx.accounts.queue_proposal()?;
+
+        Ok(())
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        // Tally votes and pay out proposal cost if it passed
+        ctx.accounts.execute_proposal()?;
+
+        Ok(())
+    }
+
+    pub fn add_proposal_instruction(
+        ctx: Context<AddProposalInstruction>,
+        program_id: Pubkey,
+        accounts: Vec<InstructionAccount>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        // Attach an instruction the dao signs for once the proposal is executed
+        ctx.accounts
+            .add_instruction(ctx.bumps, program_id, accounts, data)?;
+
+        Ok(())
+    }
+
+    pub fn execute_proposal_instruction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposalInstruction<'info>>,
+    ) -> Result<()> {
+        // Run the next instruction of an executed proposal signed by the dao
+        ctx.accounts.execute_instruction(ctx.remaining_accounts)?;
+
+        Ok(())
+    }
+
     // Instruction handlers
-    pub fn vote_on_proposal(
-        ctx: Context<Voter>,
+    pub fn vote_on_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Voter<'info>>,
         vote_type: VoteType,
         tokens_to_stake: u64,
         vote_seed: u64,
@@ -176,15 +414,73 @@ pub mod daoverse {
         ctx.accounts
             .cast_vote(ctx.bumps, vote_type, tokens_to_stake, vote_seed)?;
 
+        // Add voting power delegated to the voter
+        ctx.accounts
+            .cast_delegated_votes(ctx.remaining_accounts, vote_type, vote_seed)?;
+
         Ok(())
     }
 
-    pub fn claim_stake_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+    pub fn change_vote(
+        ctx: Context<ChangeVote>,
+        vote_type: VoteType,
+        tokens_to_stake: u64,
+    ) -> Result<()> {
+        // Switch sides or adjust the stake before voting ends
+        ctx.accounts.change_vote(vote_type, tokens_to_stake)?;
+
+        Ok(())
+    }
+
+    pub fn withdraw_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawVote<'info>>,
+    ) -> Result<()> {
+        // Reclaim the stake and take the vote off the tally
+        ctx.accounts.withdraw_vote(ctx.remaining_accounts)?;
+
+        Ok(())
+    }
+
+    //delegation
+    pub fn delegate_votes(ctx: Context<DelegateVotes>, dao_scope: Pubkey) -> Result<()> {
+        ctx.accounts.delegate_votes(ctx.bumps, dao_scope)?;
+
+        Ok(())
+    }
+
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        ctx.accounts.revoke_delegation()?;
+
+        Ok(())
+    }
+
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        // Settle once voting ends and snapshot the reward pool
         ctx.accounts.finalize_proposal()?;
 
+        Ok(())
+    }
+
+    pub fn claim_stake_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
         // Claim rewards
         ctx.accounts.claim_rewards()?;
 
         Ok(())
     }
+
+    pub fn close_vote(ctx: Context<CloseVote>) -> Result<()> {
+        // Reclaim the rent of a paid out vote record
+        ctx.accounts.close_vote()?;
+
+        Ok(())
+    }
+
+    pub fn close_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseProposal<'info>>,
+    ) -> Result<()> {
+        // Reclaim the rent of a finished proposal and its instruction records
+        ctx.accounts.close_proposal(ctx.remaining_accounts)?;
+
+        Ok(())
+    }
 }
@@ -1,3 +1,4 @@
+use crate::state::DaoMemberState;
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
@@ -7,6 +8,31 @@ pub enum GovernanceModel {
     Hybrid,
 }
 
+// hybrid voting multiplies token weight by up to 2x based on reputation
+pub const HYBRID_REPUTATION_CAP: u64 = 100;
+
+impl GovernanceModel {
+    pub fn uses_reputation(&self) -> bool {
+        !matches!(self, GovernanceModel::TokenBased)
+    }
+
+    pub fn uses_token_balance(&self) -> bool {
+        !matches!(self, GovernanceModel::ReputationBased)
+    }
+
+    // Combine the voting model weight with the member reputation
+    pub fn vote_weight(&self, token_weight: u64, reputation: u64) -> Option<u64> {
+        match self {
+            GovernanceModel::TokenBased => Some(token_weight),
+            GovernanceModel::ReputationBased => Some(reputation),
+            GovernanceModel::Hybrid => {
+                let multiplier = 100 + reputation.min(HYBRID_REPUTATION_CAP) as u128;
+                u64::try_from(token_weight as u128 * multiplier / 100).ok()
+            }
+        }
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum VotingModel {
     OneTokenOneVote,
@@ -15,6 +41,69 @@ pub enum VotingModel {
     HolderBased,
 }
 
+impl VotingModel {
+    // Vote weight for a stake given in base units of the dao mint
+    // returns None if the weight cannot be computed without overflowing
+    pub fn vote_weight(&self, tokens_staked: u64, decimals: u8) -> Option<u64> {
+        match self {
+            // one vote per whole token staked
+            VotingModel::OneTokenOneVote => {
+                tokens_staked.checked_div(10u64.checked_pow(decimals as u32)?)
+            }
+            VotingModel::Quadratic => Some(integer_sqrt(tokens_staked)),
+            VotingModel::WeightedToken => Some(tokens_staked),
+            // one vote per member regardless of stake
+            VotingModel::HolderBased => Some(1),
+        }
+    }
+}
+
+// floor(sqrt(value)) using newton's method
+pub fn integer_sqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x / 2 + 1;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
+// Convert a whole token amount to base units of a mint with the given decimals
+pub fn to_base_units(whole_tokens: u64, decimals: u8) -> Option<u64> {
+    whole_tokens.checked_mul(10u64.checked_pow(decimals as u32)?)
+}
+
+// Share of an amount for the given weight out of the total, rounded down
+pub fn pro_rata(amount: u64, weight: u64, total_weight: u64) -> Option<u64> {
+    if total_weight == 0 {
+        return Some(0);
+    }
+    u64::try_from(amount as u128 * weight as u128 / total_weight as u128).ok()
+}
+
+// depositors of a dissolved dao have 90 days to claim before the rest is swept
+pub const DISSOLUTION_CLAIM_PERIOD: i64 = 90 * 24 * 60 * 60;
+
+// Share of a dissolved dao's treasury for a deposit, the last deposit to claim
+// takes everything left while earlier claims leave the reserve behind
+// weights are governance deposit balances at the dissolved slot, not memberships
+pub fn dissolution_share(
+    pool: u64,
+    reserve: u64,
+    weight: u64,
+    remaining_weight: u64,
+) -> Option<u64> {
+    if weight >= remaining_weight {
+        return Some(pool);
+    }
+    pro_rata(pool.saturating_sub(reserve), weight, remaining_weight)
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum RewardModel {
     ProportionalDistribution,
@@ -23,12 +112,142 @@ pub enum RewardModel {
     NoRewards,
 }
 
+// stake forfeits are expressed in basis points
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+impl RewardModel {
+    // Share of the proposal reward pool a voter earns for their stake
+    pub fn reward_weight(&self, tokens_staked: u64, reputation: u64) -> Option<u64> {
+        match self {
+            // contribution based stakes count up to 2x with the member's reputation
+            RewardModel::ContributionBased => {
+                let multiplier = 100u128 + reputation.min(HYBRID_REPUTATION_CAP) as u128;
+                u64::try_from(tokens_staked as u128 * multiplier / 100).ok()
+            }
+            RewardModel::ProportionalDistribution | RewardModel::MilestoneBasedVesting => {
+                Some(tokens_staked)
+            }
+            RewardModel::NoRewards => Some(0),
+        }
+    }
+}
+
+// Standard settlement refunds every stake, conviction settlement takes
+// forfeit_bps of the stake of voters on the losing side
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum SettlementMode {
+    Standard,
+    Conviction,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum ForfeitRecipient {
+    WinningSide,
+    Treasury,
+}
+
+// Dao reward budget and rate, rewards are funded from the treasury
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RewardConfig {
+    // reward pool of each settled proposal in base units, shared among
+    // its voters by stake whatever the total stake
+    pub reward_per_proposal: u64,
+    // total rewards the treasury can fund, in base units
+    pub reward_budget: u64,
+    // milestone vesting releases rewards in equal tranches over the vesting period
+    pub vesting_period: u64,
+    pub vesting_milestones: u8,
+    pub settlement_mode: SettlementMode,
+    pub forfeit_bps: u16,
+    pub forfeit_recipient: ForfeitRecipient,
+}
+
+impl RewardConfig {
+    pub fn is_valid(&self) -> bool {
+        self.forfeit_bps as u64 <= BPS_DENOMINATOR && self.vesting_milestones > 0
+    }
+
+    // share of losing stakes forfeited, 0 under standard settlement
+    pub fn forfeit_bps(&self) -> u16 {
+        match self.settlement_mode {
+            SettlementMode::Conviction => self.forfeit_bps,
+            SettlementMode::Standard => 0,
+        }
+    }
+
+    // Reward pool for a proposal, capped by what is left of the budget
+    pub fn reward_pool(&self, rewards_allocated: u64) -> u64 {
+        let remaining_budget = self.reward_budget.saturating_sub(rewards_allocated);
+        self.reward_per_proposal.min(remaining_budget)
+    }
+
+    // Part of a reward that has vested, only milestone vesting holds rewards back
+    pub fn vested_amount(
+        &self,
+        reward_model: &RewardModel,
+        reward: u64,
+        vesting_start: i64,
+        current_time: i64,
+    ) -> Option<u64> {
+        if *reward_model != RewardModel::MilestoneBasedVesting || self.vesting_period == 0 {
+            return Some(reward);
+        }
+
+        let milestones = self.vesting_milestones as u128;
+        let elapsed = current_time.saturating_sub(vesting_start).max(0) as u128;
+        let reached = (elapsed * milestones / self.vesting_period as u128).min(milestones);
+
+        u64::try_from(reward as u128 * reached / milestones).ok()
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct VotingThreshold {
     pub quorum_percentage: u8,
     pub approval_percentage: u8,
     pub min_voting_period: u64,
     pub max_voting_period: u64,
+    // seconds a succeeded proposal waits in the queue before it can be executed
+    pub execution_delay: u64,
+}
+
+impl VotingThreshold {
+    // quorum is the share of dao members that took part in the vote
+    pub fn quorum_reached(&self, votes_cast: u64, member_count: u64) -> bool {
+        (votes_cast as u128) * 100 >= (self.quorum_percentage as u128) * (member_count as u128)
+    }
+
+    // approval is the share of yes votes among all non-abstaining votes
+    pub fn is_approved(&self, votes_yes: u64, votes_no: u64) -> bool {
+        let votes_cast = votes_yes as u128 + votes_no as u128;
+        if votes_cast == 0 {
+            return false;
+        }
+        (votes_yes as u128) * 100 >= (self.approval_percentage as u128) * votes_cast
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.quorum_percentage <= 100
+            && self.approval_percentage <= 100
+            && self.min_voting_period <= self.max_voting_period
+    }
+
+    // a proposal opened now must stay open between the min and max voting period
+    pub fn is_valid_voting_window(&self, current_time: i64, voting_end_time: i64) -> bool {
+        let period = match voting_end_time.checked_sub(current_time) {
+            Some(period) if period > 0 => period as u64,
+            _ => return false,
+        };
+        (self.min_voting_period..=self.max_voting_period).contains(&period)
+    }
+}
+
+// Dao token balances required to join, propose and vote, in whole tokens
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct EligibilityThreshold {
+    pub min_member_tokens: u64,
+    pub min_proposer_tokens: u64,
+    pub min_voter_tokens: u64,
 }
 
 #[account]
@@ -49,5 +268,250 @@ pub struct DaoConfig {
     pub voting_model: VotingModel,
     pub reward_model: RewardModel,
     pub voting_threshold: VotingThreshold,
+    pub eligibility_threshold: EligibilityThreshold,
+    // can cancel queued proposals during the execution delay
+    pub guardian: Pubkey,
     pub member_count: u64,
+    pub reward_config: RewardConfig,
+    // rewards set aside for settled proposals, counted against the budget
+    pub rewards_allocated: u64,
+    // sum of all governance deposits in the dao
+    pub total_deposited: u64,
+    // set once a proposal dissolves the dao, the dao mint and sol treasuries
+    // are shared among depositors by their balance at dissolved_slot
+    // dissolution_pool is the dao mint treasury at that point and
+    // dissolution_weight the deposits that have not claimed yet
+    pub dissolved: bool,
+    pub dissolved_slot: u64,
+    pub dissolution_pool: u64,
+    pub dissolution_weight: u64,
+    // whole dao tokens a proposer locks until the proposal is finalized,
+    // slashed to the treasury if the vote does not reach quorum
+    pub proposal_deposit: u64,
+    // seconds new proposals stay in draft before voting opens
+    pub discussion_period: u64,
+    // receives what depositors cannot claim from a dissolved dao
+    pub dissolution_recipient: Pubkey,
+    pub dissolved_at: i64,
+}
+
+impl DaoConfig {
+    // the creator hands dao administration over to governance
+    // once the first proposal has been created
+    pub fn is_live(&self) -> bool {
+        self.total_proposals > 0
+    }
+
+    // depositors can claim until every deposit has claimed or the claim period is over
+    pub fn dissolution_claims_open(&self, current_time: i64) -> bool {
+        self.dissolution_weight > 0
+            && current_time < self.dissolved_at.saturating_add(DISSOLUTION_CLAIM_PERIOD)
+    }
+
+    // Reward weight of a member staking the given token amount
+    pub fn member_reward_weight(
+        &self,
+        member: &DaoMemberState,
+        tokens_staked: u64,
+        current_time: i64,
+    ) -> Option<u64> {
+        let reputation = if self.reward_model == RewardModel::ContributionBased {
+            member.reputation_score(current_time)
+        } else {
+            0
+        };
+
+        self.reward_model.reward_weight(tokens_staked, reputation)
+    }
+
+    // Vote weight of a member staking the given token amount
+    pub fn member_vote_weight(
+        &self,
+        member: &DaoMemberState,
+        tokens: u64,
+        decimals: u8,
+        current_time: i64,
+    ) -> Option<u64> {
+        let token_weight = self.voting_model.vote_weight(tokens, decimals)?;
+
+        // Reputation based and hybrid daos factor in the member's history
+        let reputation = if self.governance_model.uses_reputation() {
+            member.reputation_score(current_time)
+        } else {
+            0
+        };
+
+        self.governance_model.vote_weight(token_weight, reputation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_base_units_scales_by_decimals() {
+        assert_eq!(to_base_units(150, 6), Some(150_000_000));
+        assert_eq!(to_base_units(150, 0), Some(150));
+        assert_eq!(to_base_units(0, 9), Some(0));
+        assert_eq!(to_base_units(u64::MAX, 1), None);
+        assert_eq!(to_base_units(1, 20), None);
+    }
+
+    #[test]
+    fn pro_rata_rounds_down() {
+        assert_eq!(pro_rata(1_000, 1, 3), Some(333));
+        assert_eq!(pro_rata(1_000, 3, 3), Some(1_000));
+        assert_eq!(pro_rata(1_000, 5, 0), Some(0));
+    }
+
+    #[test]
+    fn last_dissolution_claim_takes_the_rest() {
+        // 1_000 left for deposits of 30 and 70
+        assert_eq!(dissolution_share(1_000, 0, 30, 100), Some(300));
+        assert_eq!(dissolution_share(701, 0, 70, 70), Some(701));
+        // earlier claims leave the reserve
+        assert_eq!(dissolution_share(1_100, 100, 50, 100), Some(500));
+        assert_eq!(dissolution_share(50, 100, 50, 100), Some(0));
+        assert_eq!(dissolution_share(600, 100, 50, 50), Some(600));
+    }
+
+    #[test]
+    fn integer_sqrt_floors() {
+        assert_eq!(integer_sqrt(0), 0);
+        assert_eq!(integer_sqrt(1), 1);
+        assert_eq!(integer_sqrt(3), 1);
+        assert_eq!(integer_sqrt(4), 2);
+        assert_eq!(integer_sqrt(99), 9);
+        assert_eq!(integer_sqrt(100), 10);
+        assert_eq!(integer_sqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn one_token_one_vote_counts_whole_tokens() {
+        let model = VotingModel::OneTokenOneVote;
+        assert_eq!(model.vote_weight(150_000_000, 6), Some(150));
+        assert_eq!(model.vote_weight(1_999_999, 6), Some(1));
+        assert_eq!(model.vote_weight(999_999, 6), Some(0));
+        assert_eq!(model.vote_weight(42, 0), Some(42));
+        assert_eq!(model.vote_weight(42, 20), None);
+    }
+
+    #[test]
+    fn quadratic_uses_square_root_of_stake() {
+        let model = VotingModel::Quadratic;
+        assert_eq!(model.vote_weight(100_000_000, 6), Some(10_000));
+        assert_eq!(model.vote_weight(400_000_000, 6), Some(20_000));
+        assert_eq!(model.vote_weight(0, 6), Some(0));
+    }
+
+    #[test]
+    fn weighted_token_is_linear_in_stake() {
+        let model = VotingModel::WeightedToken;
+        assert_eq!(model.vote_weight(100_000_000, 6), Some(100_000_000));
+        assert_eq!(model.vote_weight(u64::MAX, 6), Some(u64::MAX));
+    }
+
+    #[test]
+    fn holder_based_gives_one_vote_per_member() {
+        let model = VotingModel::HolderBased;
+        assert_eq!(model.vote_weight(100_000_000, 6), Some(1));
+        assert_eq!(model.vote_weight(u64::MAX, 6), Some(1));
+    }
+
+    #[test]
+    fn governance_model_combines_reputation() {
+        assert_eq!(GovernanceModel::TokenBased.vote_weight(500, 40), Some(500));
+        assert_eq!(
+            GovernanceModel::ReputationBased.vote_weight(500, 40),
+            Some(40)
+        );
+        assert_eq!(GovernanceModel::Hybrid.vote_weight(500, 40), Some(700));
+        // reputation can at most double the token weight
+        assert_eq!(GovernanceModel::Hybrid.vote_weight(500, 1_000), Some(1_000));
+        assert_eq!(GovernanceModel::Hybrid.vote_weight(u64::MAX, 1), None);
+    }
+
+    #[test]
+    fn approval_uses_weighted_tallies() {
+        let threshold = VotingThreshold {
+            quorum_percentage: 50,
+            approval_percentage: 60,
+            min_voting_period: 0,
+            max_voting_period: 0,
+            execution_delay: 0,
+        };
+        assert!(threshold.is_approved(60, 40));
+        assert!(!threshold.is_approved(59, 41));
+        assert!(!threshold.is_approved(0, 0));
+        assert!(threshold.quorum_reached(5, 10));
+        assert!(!threshold.quorum_reached(4, 10));
+    }
+
+    #[test]
+    fn voting_window_must_fit_the_threshold_periods() {
+        let threshold = VotingThreshold {
+            quorum_percentage: 50,
+            approval_percentage: 60,
+            min_voting_period: 100,
+            max_voting_period: 1_000,
+            execution_delay: 0,
+        };
+        assert!(threshold.is_valid_voting_window(50, 150));
+        assert!(threshold.is_valid_voting_window(50, 1_050));
+        assert!(!threshold.is_valid_voting_window(50, 149));
+        assert!(!threshold.is_valid_voting_window(50, 1_051));
+        assert!(!threshold.is_valid_voting_window(50, 40));
+        assert!(!threshold.is_valid_voting_window(i64::MAX, i64::MIN));
+    }
+
+    #[test]
+    fn reward_pool_is_capped_by_budget() {
+        let config = RewardConfig {
+            reward_per_proposal: 800,
+            reward_budget: 1_000,
+            vesting_period: 0,
+            vesting_milestones: 1,
+            settlement_mode: SettlementMode::Standard,
+            forfeit_bps: 0,
+            forfeit_recipient: ForfeitRecipient::Treasury,
+        };
+        assert_eq!(config.reward_pool(0), 800);
+        assert_eq!(config.reward_pool(500), 500);
+        assert_eq!(config.reward_pool(1_500), 0);
+    }
+
+    #[test]
+    fn contribution_based_rewards_scale_with_reputation() {
+        let model = RewardModel::ContributionBased;
+        assert_eq!(model.reward_weight(1_000, 0), Some(1_000));
+        assert_eq!(model.reward_weight(1_000, 50), Some(1_500));
+        assert_eq!(model.reward_weight(1_000, 500), Some(2_000));
+        assert_eq!(RewardModel::NoRewards.reward_weight(1_000, 50), Some(0));
+    }
+
+    #[test]
+    fn milestone_vesting_releases_tranches() {
+        let config = RewardConfig {
+            reward_per_proposal: 2_000,
+            reward_budget: u64::MAX,
+            vesting_period: 400,
+            vesting_milestones: 4,
+            settlement_mode: SettlementMode::Standard,
+            forfeit_bps: 0,
+            forfeit_recipient: ForfeitRecipient::Treasury,
+        };
+        let model = RewardModel::MilestoneBasedVesting;
+        assert_eq!(config.vested_amount(&model, 1_000, 100, 100), Some(0));
+        assert_eq!(config.vested_amount(&model, 1_000, 100, 299), Some(250));
+        assert_eq!(config.vested_amount(&model, 1_000, 100, 300), Some(500));
+        assert_eq!(
+            config.vested_amount(&model, 1_000, 100, 10_000),
+            Some(1_000)
+        );
+        assert_eq!(
+            config.vested_amount(&RewardModel::ProportionalDistribution, 1_000, 100, 100),
+            Some(1_000)
+        );
+    }
 }
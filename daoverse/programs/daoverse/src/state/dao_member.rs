@@ -1,15 +1,218 @@
 use anchor_lang::prelude::*;
 
+// Reputation is earned through participation in the dao
+// every member starts with a base point so new members can still vote
+pub const REPUTATION_BASE: u64 = 1;
+pub const REPUTATION_PER_APPROVED_PROPOSAL: u64 = 10;
+pub const REPUTATION_PER_VOTE: u64 = 1;
+// one point per full day of membership, capped at a year
+pub const REPUTATION_TENURE_CAP_DAYS: u64 = 365;
+// minimum reputation to create proposals in reputation based and hybrid daos
+pub const MIN_PROPOSER_REPUTATION: u64 = 10;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+// vote-escrow locks run from a week up to four years
+pub const MIN_LOCK_DURATION: i64 = 7 * SECONDS_PER_DAY;
+pub const MAX_LOCK_DURATION: i64 = 4 * 365 * SECONDS_PER_DAY;
+// a max length lock counts 4x, decaying to 1x at unlock
+pub const MAX_LOCK_MULTIPLIER: u64 = 4;
+
+pub const MAX_MEMBER_NAME_LEN: usize = 32;
+pub const MAX_MEMBER_DESCRIPTION_LEN: usize = 200;
+
+// lock changes kept per member, the oldest checkpoint is dropped when full
+pub const MAX_LOCK_CHECKPOINTS: usize = 32;
+
+// Escrow lock at the end of a slot
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct LockCheckpoint {
+    pub slot: u64,
+    pub locked_amount: u64,
+    pub lock_end: i64,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct DaoMemberState {
-    pub member_seed: u64,
     pub dao_member: Pubkey,
     pub bump: u8,
+    #[max_len(MAX_MEMBER_NAME_LEN)]
+    pub member_name: String,
+    #[max_len(MAX_MEMBER_DESCRIPTION_LEN)]
+    pub member_description: String,
+    // participation stats below are only updated by the program
     pub created_proposals: u64,
     pub approved_proposals: u64,
     pub total_rewards: u64,
     pub total_votes: u64,
     pub dao_member_balance: u64,
     pub dao_joined: Pubkey,
+    pub joined_at: i64,
+    // vote-escrow lock, held in the member's escrow vault
+    pub locked_amount: u64,
+    pub lock_end: i64,
+    // lock history, proposals read the lock at their snapshot slot
+    #[max_len(MAX_LOCK_CHECKPOINTS)]
+    pub lock_checkpoints: Vec<LockCheckpoint>,
+}
+
+impl DaoMemberState {
+    pub fn tenure_days(&self, current_time: i64) -> u64 {
+        let days = current_time.saturating_sub(self.joined_at) / SECONDS_PER_DAY;
+        (days.max(0) as u64).min(REPUTATION_TENURE_CAP_DAYS)
+    }
+
+    pub fn reputation_score(&self, current_time: i64) -> u64 {
+        REPUTATION_BASE
+            .saturating_add(
+                self.approved_proposals
+                    .saturating_mul(REPUTATION_PER_APPROVED_PROPOSAL),
+            )
+            .saturating_add(self.total_votes.saturating_mul(REPUTATION_PER_VOTE))
+            .saturating_add(self.tenure_days(current_time))
+    }
+
+    // Time-weighted power of the escrow lock at the given time
+    // the bonus decays linearly with the time left until unlock
+    pub fn escrow_power(&self, current_time: i64) -> Option<u64> {
+        lock_power(self.locked_amount, self.lock_end, current_time)
+    }
+
+    // Escrow power for a proposal snapshot, read from the lock held at the
+    // end of the snapshot slot so later changes do not count
+    // slots before the retained history count as no lock
+    pub fn escrow_power_at(&self, snapshot_slot: u64, snapshot_time: i64) -> Option<u64> {
+        match self
+            .lock_checkpoints
+            .iter()
+            .rev()
+            .find(|checkpoint| checkpoint.slot <= snapshot_slot)
+        {
+            Some(checkpoint) => {
+                lock_power(checkpoint.locked_amount, checkpoint.lock_end, snapshot_time)
+            }
+            None => Some(0),
+        }
+    }
+
+    // Record the current lock as the lock at the end of the slot
+    pub fn checkpoint_lock(&mut self, slot: u64) {
+        if let Some(last) = self.lock_checkpoints.last_mut() {
+            if last.slot == slot {
+                last.locked_amount = self.locked_amount;
+                last.lock_end = self.lock_end;
+                return;
+            }
+        }
+
+        if self.lock_checkpoints.len() == MAX_LOCK_CHECKPOINTS {
+            self.lock_checkpoints.remove(0);
+        }
+        self.lock_checkpoints.push(LockCheckpoint {
+            slot,
+            locked_amount: self.locked_amount,
+            lock_end: self.lock_end,
+        });
+    }
+}
+
+fn lock_power(locked_amount: u64, lock_end: i64, current_time: i64) -> Option<u64> {
+    let remaining = lock_end
+        .saturating_sub(current_time)
+        .clamp(0, MAX_LOCK_DURATION) as u128;
+    let bonus = (locked_amount as u128)
+        .checked_mul(MAX_LOCK_MULTIPLIER as u128 - 1)?
+        .checked_mul(remaining)?
+        / MAX_LOCK_DURATION as u128;
+
+    u64::try_from((locked_amount as u128).checked_add(bonus)?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(approved_proposals: u64, total_votes: u64, joined_at: i64) -> DaoMemberState {
+        DaoMemberState {
+            dao_member: Pubkey::default(),
+            bump: 0,
+            member_name: String::new(),
+            member_description: String::new(),
+            created_proposals: 0,
+            approved_proposals,
+            total_rewards: 0,
+            total_votes,
+            dao_member_balance: 0,
+            dao_joined: Pubkey::default(),
+            joined_at,
+            locked_amount: 0,
+            lock_end: 0,
+            lock_checkpoints: Vec::new(),
+        }
+    }
+
+    fn locked(amount: u64, lock_end: i64, slot: u64) -> DaoMemberState {
+        let mut member = DaoMemberState {
+            locked_amount: amount,
+            lock_end,
+            ..member(0, 0, 0)
+        };
+        member.checkpoint_lock(slot);
+        member
+    }
+
+    #[test]
+    fn new_member_has_base_reputation() {
+        assert_eq!(member(0, 0, 1_000).reputation_score(1_000), REPUTATION_BASE);
+    }
+
+    #[test]
+    fn reputation_adds_proposals_votes_and_tenure() {
+        let now = 30 * SECONDS_PER_DAY;
+        // 1 base + 2 * 10 approved + 5 votes + 30 days
+        assert_eq!(member(2, 5, 0).reputation_score(now), 56);
+    }
+
+    #[test]
+    fn tenure_is_capped() {
+        let now = 1_000 * SECONDS_PER_DAY;
+        assert_eq!(member(0, 0, 0).tenure_days(now), REPUTATION_TENURE_CAP_DAYS);
+        // clock before join time never underflows
+        assert_eq!(member(0, 0, now).tenure_days(0), 0);
+    }
+
+    #[test]
+    fn escrow_power_decays_to_the_locked_amount() {
+        let lock = locked(1_000, MAX_LOCK_DURATION, 0);
+        assert_eq!(lock.escrow_power(0), Some(4_000));
+        assert_eq!(lock.escrow_power(MAX_LOCK_DURATION / 2), Some(2_500));
+        assert_eq!(lock.escrow_power(MAX_LOCK_DURATION), Some(1_000));
+        // expired locks still count until unlocked
+        assert_eq!(lock.escrow_power(MAX_LOCK_DURATION * 2), Some(1_000));
+        assert_eq!(member(0, 0, 0).escrow_power(0), Some(0));
+    }
+
+    #[test]
+    fn escrow_changes_after_the_snapshot_do_not_count() {
+        let lock = locked(1_000, MAX_LOCK_DURATION, 50);
+        assert_eq!(lock.escrow_power_at(49, 0), Some(0));
+        assert_eq!(lock.escrow_power_at(50, 0), Some(4_000));
+    }
+
+    #[test]
+    fn relocking_keeps_the_power_of_older_snapshots() {
+        let mut lock = locked(1_000, MAX_LOCK_DURATION, 50);
+        lock.locked_amount = 3_000;
+        lock.checkpoint_lock(60);
+        assert_eq!(lock.escrow_power_at(55, 0), Some(4_000));
+        assert_eq!(lock.escrow_power_at(60, 0), Some(12_000));
+
+        // unlocking only removes the power from later snapshots
+        lock.locked_amount = 0;
+        lock.lock_end = 0;
+        lock.checkpoint_lock(70);
+        assert_eq!(lock.escrow_power_at(65, 0), Some(12_000));
+        assert_eq!(lock.escrow_power_at(70, 0), Some(0));
+    }
 }
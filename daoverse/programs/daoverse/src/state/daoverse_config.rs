@@ -1,3 +1,4 @@
+use crate::state::BPS_DENOMINATOR;
 use anchor_lang::prelude::*;
 
 #[account]
@@ -6,11 +7,58 @@ use anchor_lang::prelude::*;
 pub struct DaoverseConfig {
     pub admin: Pubkey,
     pub daoverse_mint: Pubkey,
+    // fee and creator balance are in whole daoverse tokens
     pub dao_creation_fee: u64,
+    pub min_creator_tokens: u64,
     pub bump: u8,
     pub daoverse_treasury_balance: u64,
     #[max_len(32)]
     pub admin_name: String,
     #[max_len(200)]
     pub daoverse_description: String,
+    // set by the admin, takes over once accepted
+    pub pending_admin: Option<Pubkey>,
+    // share of each creation fee sent to the beneficiary instead of the treasury
+    pub fee_beneficiary: Pubkey,
+    pub fee_split_bps: u16,
+    // daos registered so far, each has a DaoIndex entry
+    pub dao_count: u64,
+}
+
+impl DaoverseConfig {
+    // Beneficiary share of a creation fee, the rest goes to the treasury
+    pub fn beneficiary_share(&self, fee: u64) -> Option<u64> {
+        u64::try_from(fee as u128 * self.fee_split_bps as u128 / BPS_DENOMINATOR as u128).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(fee_split_bps: u16) -> DaoverseConfig {
+        DaoverseConfig {
+            admin: Pubkey::default(),
+            daoverse_mint: Pubkey::default(),
+            dao_creation_fee: 0,
+            min_creator_tokens: 0,
+            bump: 0,
+            daoverse_treasury_balance: 0,
+            admin_name: String::new(),
+            daoverse_description: String::new(),
+            pending_admin: None,
+            fee_beneficiary: Pubkey::default(),
+            fee_split_bps,
+            dao_count: 0,
+        }
+    }
+
+    #[test]
+    fn beneficiary_share_follows_the_split() {
+        assert_eq!(config(0).beneficiary_share(1_000), Some(0));
+        assert_eq!(config(2_000).beneficiary_share(1_000), Some(200));
+        assert_eq!(config(10_000).beneficiary_share(1_000), Some(1_000));
+        // rounds down in favour of the treasury
+        assert_eq!(config(3_333).beneficiary_share(10), Some(3));
+    }
 }
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+// accounts passed per delegator when a delegate votes
+pub const DELEGATION_ACCOUNTS_LEN: usize = 4;
+
+// Delegation of voting power from a member to a delegate
+// revoking the delegation closes this account
+#[account]
+#[derive(InitSpace)]
+pub struct DelegationState {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    // dao the delegation applies to, Pubkey::default() for every dao
+    pub dao: Pubkey,
+    pub bump: u8,
+    pub delegated_at: i64,
+}
+
+impl DelegationState {
+    pub fn covers_dao(&self, dao: &Pubkey) -> bool {
+        self.dao == Pubkey::default() || self.dao == *dao
+    }
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+// balance changes kept per deposit, the oldest checkpoint is dropped when full
+pub const MAX_CHECKPOINTS: usize = 32;
+
+// Deposited balance at the end of a slot
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct Checkpoint {
+    pub slot: u64,
+    pub amount: u64,
+}
+
+// Governance tokens a member deposited in a dao, voting power is read
+// from the checkpoint history at the proposal snapshot slot
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceDepositState {
+    pub owner: Pubkey,
+    pub dao: Pubkey,
+    pub bump: u8,
+    pub amount: u64,
+    #[max_len(MAX_CHECKPOINTS)]
+    pub checkpoints: Vec<Checkpoint>,
+    // share of a dissolved dao's treasury was paid out
+    pub dissolution_claimed: bool,
+}
+
+impl GovernanceDepositState {
+    // Record the current amount as the balance at the end of the slot
+    pub fn checkpoint(&mut self, slot: u64) {
+        if let Some(last) = self.checkpoints.last_mut() {
+            if last.slot == slot {
+                last.amount = self.amount;
+                return;
+            }
+        }
+
+        if self.checkpoints.len() == MAX_CHECKPOINTS {
+            self.checkpoints.remove(0);
+        }
+        self.checkpoints.push(Checkpoint {
+            slot,
+            amount: self.amount,
+        });
+    }
+
+    // Deposited balance at the end of the given slot
+    // slots before the retained history count as no deposit
+    pub fn balance_at(&self, slot: u64) -> u64 {
+        self.checkpoints
+            .iter()
+            .rev()
+            .find(|checkpoint| checkpoint.slot <= slot)
+            .map_or(0, |checkpoint| checkpoint.amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deposit() -> GovernanceDepositState {
+        GovernanceDepositState {
+            owner: Pubkey::default(),
+            dao: Pubkey::default(),
+            bump: 0,
+            amount: 0,
+            checkpoints: Vec::new(),
+            dissolution_claimed: false,
+        }
+    }
+
+    #[test]
+    fn balance_is_read_at_the_snapshot_slot() {
+        let mut d = deposit();
+        d.amount = 100;
+        d.checkpoint(10);
+        d.amount = 40;
+        d.checkpoint(20);
+
+        assert_eq!(d.balance_at(9), 0);
+        assert_eq!(d.balance_at(10), 100);
+        assert_eq!(d.balance_at(19), 100);
+        assert_eq!(d.balance_at(20), 40);
+        assert_eq!(d.balance_at(u64::MAX), 40);
+    }
+
+    #[test]
+    fn same_slot_changes_share_a_checkpoint() {
+        let mut d = deposit();
+        d.amount = 100;
+        d.checkpoint(10);
+        d.amount = 0;
+        d.checkpoint(10);
+
+        assert_eq!(d.checkpoints.len(), 1);
+        assert_eq!(d.balance_at(10), 0);
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let mut d = deposit();
+        for slot in 0..(MAX_CHECKPOINTS as u64 + 5) {
+            d.amount = slot;
+            d.checkpoint(slot);
+        }
+
+        assert_eq!(d.checkpoints.len(), MAX_CHECKPOINTS);
+        assert_eq!(d.balance_at(0), 0);
+        assert_eq!(
+            d.balance_at(MAX_CHECKPOINTS as u64 + 4),
+            MAX_CHECKPOINTS as u64 + 4
+        );
+    }
+}
@@ -12,3 +12,18 @@ pub use proposer_config::*;
 
 pub mod vote_config;
 pub use vote_config::*;
+
+pub mod delegation_config;
+pub use delegation_config::*;
+
+pub mod proposal_instruction_config;
+pub use proposal_instruction_config::*;
+
+pub mod governance_deposit_config;
+pub use governance_deposit_config::*;
+
+pub mod role_config;
+pub use role_config::*;
+
+pub mod registry_config;
+pub use registry_config::*;
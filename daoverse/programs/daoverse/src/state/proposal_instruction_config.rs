@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+// limits keep a single instruction within one transaction
+pub const MAX_INSTRUCTION_ACCOUNTS: usize = 16;
+pub const MAX_INSTRUCTION_DATA_LEN: usize = 512;
+
+// Account meta of a proposal instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct InstructionAccount {
+    pub pubkey: Pubkey,
+    // the dao pda signs through the program, other signers must sign the transaction
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+// Serialized instruction the dao signs for once its proposal is executed
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalInstructionState {
+    pub proposal: Pubkey,
+    // proposal_index of the proposal, records left over from a closed
+    // proposal at the same address never match a re-created one
+    pub proposal_index: u64,
+    // instructions run in index order
+    pub index: u16,
+    pub program_id: Pubkey,
+    #[max_len(MAX_INSTRUCTION_ACCOUNTS)]
+    pub accounts: Vec<InstructionAccount>,
+    #[max_len(MAX_INSTRUCTION_DATA_LEN)]
+    pub data: Vec<u8>,
+    pub executed: bool,
+    pub bump: u8,
+}
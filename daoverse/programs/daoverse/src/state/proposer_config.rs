@@ -1,20 +1,764 @@
+use crate::errors::ErrorCode;
+use crate::state::{VoteState, VoteType, VotingThreshold, BPS_DENOMINATOR};
 use anchor_lang::prelude::*;
 
+// how long a succeeded proposal can wait for execution before it expires
+pub const PROPOSAL_EXECUTION_WINDOW: i64 = 14 * 24 * 60 * 60;
+
+// multi-choice proposals offer between 2 and MAX_PROPOSAL_CHOICES options
+pub const MAX_PROPOSAL_CHOICES: usize = 8;
+pub const MAX_CHOICE_LABEL_LEN: usize = 32;
+
+pub const MAX_PROPOSAL_TITLE_LEN: usize = 32;
+pub const MAX_PROPOSAL_DETAILS_LEN: usize = 200;
+// off-chain proposal document, e.g. an ipfs or arweave link
+pub const MAX_METADATA_URI_LEN: usize = 200;
+
+// Proposal lifecycle
+// Draft -> Active -> Succeeded | Defeated
+// Succeeded -> Queued -> Executed | Expired
+// Draft, Active, Succeeded and Queued proposals can be Cancelled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum ProposalStatus {
+    Draft,
+    Active,
+    Succeeded,
+    Defeated,
+    Queued,
+    Executed,
+    Cancelled,
+    Expired,
+}
+
+impl ProposalStatus {
+    pub fn can_transition_to(&self, next: ProposalStatus) -> bool {
+        use ProposalStatus::*;
+
+        matches!(
+            (self, next),
+            (Draft, Active)
+                | (Draft, Cancelled)
+                | (Active, Succeeded)
+                | (Active, Defeated)
+                | (Active, Cancelled)
+                | (Succeeded, Queued)
+                | (Succeeded, Executed)
+                | (Succeeded, Expired)
+                | (Succeeded, Cancelled)
+                | (Queued, Executed)
+                | (Queued, Cancelled)
+                | (Queued, Expired)
+        )
+    }
+
+    // voting is over and the proposal outcome is known
+    pub fn is_settled(&self) -> bool {
+        !matches!(self, ProposalStatus::Draft | ProposalStatus::Active)
+    }
+
+    // no further transition can happen
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            ProposalStatus::Defeated
+                | ProposalStatus::Executed
+                | ProposalStatus::Cancelled
+                | ProposalStatus::Expired
+        )
+    }
+}
+
+// Option of a multi-choice proposal and its tally
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct ProposalChoice {
+    #[max_len(MAX_CHOICE_LABEL_LEN)]
+    pub label: String,
+    pub vote_count: u64,
+    pub weighted: u64,
+    pub staked: u64,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct ProposalState {
     pub proposal_seed: u64,
+    // dao the proposal belongs to
+    pub dao: Pubkey,
     pub proposal_owner: Pubkey,
     pub bump: u8,
 
-    #[max_len(32)]
+    #[max_len(MAX_PROPOSAL_TITLE_LEN)]
     pub proposal_title: String,
-    #[max_len(200)]
+    #[max_len(MAX_PROPOSAL_DETAILS_LEN)]
     pub proposal_details: String,
     pub proposal_cost: u64,
     pub min_token_stake: u64,
     pub vote_count_yes: u64,
     pub vote_count_no: u64,
+    // vote weights summed under the dao voting model
+    pub weighted_yes: u64,
+    pub weighted_no: u64,
     pub voting_end_time: i64,
     pub staking_vault_balance: u64,
+    // receives proposal_cost from the dao treasury once the proposal is executed
+    pub recipient: Pubkey,
+    pub status: ProposalStatus,
+    // earliest execution time once queued
+    pub eta: i64,
+    // dao execution delay when the proposal was created
+    pub execution_delay: u64,
+    // instructions attached to the proposal and how many have run
+    pub instruction_count: u16,
+    pub executed_instructions: u16,
+    // abstentions count toward quorum only
+    pub vote_count_abstain: u64,
+    pub weighted_abstain: u64,
+    // empty for yes/no proposals
+    #[max_len(MAX_PROPOSAL_CHOICES)]
+    pub choices: Vec<ProposalChoice>,
+    // set when a multi-choice proposal succeeds
+    pub winning_choice: Option<u8>,
+    // sum of the voters' reward weights under the dao reward model
+    pub total_reward_weight: u64,
+    // rewards moved from the treasury to the staking vault on finalization
+    pub reward_pool: u64,
+    pub finalized: bool,
+    pub settled_at: i64,
+    // tokens staked on each side, used for conviction settlement
+    pub staked_yes: u64,
+    pub staked_no: u64,
+    pub staked_abstain: u64,
+    // side that carried the vote, None without quorum or a winning choice
+    pub winning_vote: Option<VoteType>,
+    // conviction settlement snapshot taken when the reward pool is funded
+    pub forfeit_bps: u16,
+    pub forfeited_stake: u64,
+    pub forfeit_to_winners: bool,
+    // voting power is read from governance deposits at the end of this slot
+    pub snapshot_slot: u64,
+    // escrow locks decay up to this time for votes on the proposal
+    pub snapshot_time: i64,
+    // vote records not yet closed, the proposal can only be closed without any
+    pub open_vote_records: u64,
+    // proposer deposit held in the staking vault outside staking_vault_balance
+    pub deposit: u64,
+    pub quorum_reached: bool,
+    // full proposal text lives off-chain, clients check it against content_hash
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub metadata_uri: String,
+    pub content_hash: [u8; 32],
+    // draft proposals are discussed until voting opens at this time
+    pub voting_start_time: i64,
+    // position in the dao proposal registry, unique even when a closed
+    // proposal is re-created at the same address
+    pub proposal_index: u64,
+    // set by the first vote of any kind and kept when votes are withdrawn
+    pub voting_started: bool,
+    // dao voting threshold when the proposal was created, used to settle it
+    pub voting_threshold: VotingThreshold,
+}
+
+impl ProposalState {
+    pub fn is_multi_choice(&self) -> bool {
+        !self.choices.is_empty()
+    }
+
+    // Tally a vote type is counted in, yes/no only apply to yes/no proposals
+    // and choices only to multi-choice proposals
+    // returns the voter count, weight and stake of the side
+    fn tally_mut(&mut self, vote_type: VoteType) -> Result<(&mut u64, &mut u64, &mut u64)> {
+        let multi_choice = self.is_multi_choice();
+
+        match vote_type {
+            VoteType::Yes if !multi_choice => Ok((
+                &mut self.vote_count_yes,
+                &mut self.weighted_yes,
+                &mut self.staked_yes,
+            )),
+            VoteType::No if !multi_choice => Ok((
+                &mut self.vote_count_no,
+                &mut self.weighted_no,
+                &mut self.staked_no,
+            )),
+            VoteType::Abstain => Ok((
+                &mut self.vote_count_abstain,
+                &mut self.weighted_abstain,
+                &mut self.staked_abstain,
+            )),
+            VoteType::Choice(index) => {
+                let choice = self
+                    .choices
+                    .get_mut(index as usize)
+                    .ok_or(ErrorCode::InvalidVoteType)?;
+                Ok((
+                    &mut choice.vote_count,
+                    &mut choice.weighted,
+                    &mut choice.staked,
+                ))
+            }
+            _ => err!(ErrorCode::InvalidVoteType),
+        }
+    }
+
+    // Tokens staked on one side of the vote
+    pub fn staked_on(&self, vote_type: VoteType) -> u64 {
+        match vote_type {
+            VoteType::Yes => self.staked_yes,
+            VoteType::No => self.staked_no,
+            VoteType::Abstain => self.staked_abstain,
+            VoteType::Choice(index) => self
+                .choices
+                .get(index as usize)
+                .map_or(0, |choice| choice.staked),
+        }
+    }
+
+    // Add voters and their combined weight to one side of the tally
+    pub fn add_votes(&mut self, vote_type: VoteType, voters: u64, weight: u64) -> Result<()> {
+        let (count, weighted, _) = self.tally_mut(vote_type)?;
+
+        *count = count
+            .checked_add(voters)
+            .ok_or(ErrorCode::CalculationError)?;
+        *weighted = weighted
+            .checked_add(weight)
+            .ok_or(ErrorCode::CalculationError)?;
+        self.voting_started = true;
+
+        Ok(())
+    }
+
+    // Take voters and their combined weight off one side of the tally
+    pub fn remove_votes(&mut self, vote_type: VoteType, voters: u64, weight: u64) -> Result<()> {
+        let (count, weighted, _) = self.tally_mut(vote_type)?;
+
+        *count = count
+            .checked_sub(voters)
+            .ok_or(ErrorCode::CalculationError)?;
+        *weighted = weighted
+            .checked_sub(weight)
+            .ok_or(ErrorCode::CalculationError)?;
+
+        Ok(())
+    }
+
+    pub fn add_stake(&mut self, vote_type: VoteType, amount: u64) -> Result<()> {
+        let (_, _, staked) = self.tally_mut(vote_type)?;
+        *staked = staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::CalculationError)?;
+        Ok(())
+    }
+
+    pub fn remove_stake(&mut self, vote_type: VoteType, amount: u64) -> Result<()> {
+        let (_, _, staked) = self.tally_mut(vote_type)?;
+        *staked = staked
+            .checked_sub(amount)
+            .ok_or(ErrorCode::CalculationError)?;
+        Ok(())
+    }
+
+    // a vote loses when another side carried the proposal, abstentions never lose
+    pub fn is_losing_vote(&self, vote_type: VoteType) -> bool {
+        match self.winning_vote {
+            Some(winner) => vote_type != winner && vote_type != VoteType::Abstain,
+            None => false,
+        }
+    }
+
+    // Stake lost by everyone who voted for a losing side
+    pub fn losing_stake(&self) -> Option<u64> {
+        let winner = self.winning_vote?;
+        let total = self.choices.iter().try_fold(
+            self.staked_yes.checked_add(self.staked_no)?,
+            |total, choice| total.checked_add(choice.staked),
+        )?;
+
+        total.checked_sub(self.staked_on(winner))
+    }
+
+    // Part of a vote's stake forfeited under conviction settlement
+    // rounded up so the forfeits always cover forfeited_stake
+    pub fn stake_forfeit(&self, vote: &VoteState) -> Option<u64> {
+        if self.forfeit_bps == 0 || !self.is_losing_vote(vote.vote_type) {
+            return Some(0);
+        }
+
+        let forfeit = (vote.tokens_staked as u128 * self.forfeit_bps as u128)
+            .div_ceil(BPS_DENOMINATOR as u128);
+        u64::try_from(forfeit).ok()
+    }
+
+    // Share of the forfeited stake paid to a vote on the winning side
+    pub fn forfeit_share(&self, vote: &VoteState) -> Option<u64> {
+        let winning_stake = match self.winning_vote {
+            Some(winner) if self.forfeit_to_winners && vote.vote_type == winner => {
+                self.staked_on(winner)
+            }
+            _ => return Some(0),
+        };
+        if winning_stake == 0 {
+            return Some(0);
+        }
+
+        u64::try_from(
+            self.forfeited_stake as u128 * vote.tokens_staked as u128 / winning_stake as u128,
+        )
+        .ok()
+    }
+
+    // End the discussion period of a draft and snapshot voting power
+    pub fn open_voting(&mut self, current_time: i64, current_slot: u64) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Draft,
+            ErrorCode::InvalidProposalStatus
+        );
+        require!(
+            current_time >= self.voting_start_time,
+            ErrorCode::DiscussionNotEnded
+        );
+
+        self.snapshot_slot = current_slot;
+        self.snapshot_time = current_time;
+        self.transition_to(ProposalStatus::Active)
+    }
+
+    // votes can be cast, changed or withdrawn until voting ends
+    pub fn require_open_for_voting(&self, current_time: i64) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
+        require!(
+            current_time < self.voting_end_time,
+            ErrorCode::VotingPeriodEnded
+        );
+        Ok(())
+    }
+
+    pub fn transition_to(&mut self, next: ProposalStatus) -> Result<()> {
+        require!(
+            self.status.can_transition_to(next),
+            ErrorCode::InvalidProposalStatus
+        );
+        self.status = next;
+        Ok(())
+    }
+
+    // Queue a succeeded proposal behind the execution delay it was created with
+    pub fn queue(&mut self, current_time: i64) -> Result<()> {
+        let delay = i64::try_from(self.execution_delay).map_err(|_| ErrorCode::CalculationError)?;
+        self.eta = current_time
+            .checked_add(delay)
+            .ok_or(ErrorCode::CalculationError)?;
+        self.transition_to(ProposalStatus::Queued)
+    }
+
+    // Approved proposals must be executed within the execution window
+    // counted from their eta when queued, or from the end of voting otherwise
+    pub fn execution_deadline(&self) -> Result<i64> {
+        let window_start = if self.status == ProposalStatus::Queued {
+            self.eta
+        } else {
+            self.voting_end_time
+        };
+
+        Ok(window_start
+            .checked_add(PROPOSAL_EXECUTION_WINDOW)
+            .ok_or(ErrorCode::CalculationError)?)
+    }
+
+    // the proposer deposit is slashed when a vote ends without quorum,
+    // cancelled proposals get it back as owners can only cancel drafts
+    pub fn deposit_slashed(&self) -> bool {
+        self.status != ProposalStatus::Cancelled && !self.quorum_reached
+    }
+
+    // Finished proposals can be closed once every vote record is gone
+    // and all attached instructions of an executed proposal have run
+    pub fn can_close(&self) -> bool {
+        let instructions_done = self.status != ProposalStatus::Executed
+            || self.executed_instructions == self.instruction_count;
+
+        self.finalized && self.status.is_final() && instructions_done && self.open_vote_records == 0
+    }
+
+    // The choice with the most weight wins if it holds the approval share
+    // of all weight cast on choices, ties have no winner
+    pub fn leading_choice(&self, threshold: &VotingThreshold) -> Option<u8> {
+        let total: u128 = self.choices.iter().map(|c| c.weighted as u128).sum();
+
+        let mut leader: Option<(usize, u64)> = None;
+        let mut tied = false;
+        for (index, choice) in self.choices.iter().enumerate() {
+            match leader {
+                Some((_, weight)) if choice.weighted < weight => {}
+                Some((_, weight)) if choice.weighted == weight => tied = true,
+                _ => {
+                    leader = Some((index, choice.weighted));
+                    tied = false;
+                }
+            }
+        }
+
+        let (index, weight) = leader?;
+        if tied {
+            return None;
+        }
+
+        // weight of the other choices, always fits as total is a sum of u64
+        let others = u64::try_from(total - weight as u128).ok()?;
+        if threshold.is_approved(weight, others) {
+            u8::try_from(index).ok()
+        } else {
+            None
+        }
+    }
+
+    // Move an active proposal to Succeeded or Defeated once voting has ended
+    pub fn settle(&mut self, member_count: u64, current_time: i64) -> Result<()> {
+        require!(
+            self.status == ProposalStatus::Active,
+            ErrorCode::ProposalNotActive
+        );
+        require!(
+            current_time >= self.voting_end_time,
+            ErrorCode::VotingPeriodNotEnded
+        );
+
+        // abstentions count toward quorum
+        let mut votes_cast = self
+            .vote_count_yes
+            .checked_add(self.vote_count_no)
+            .and_then(|votes| votes.checked_add(self.vote_count_abstain))
+            .ok_or(ErrorCode::CalculationError)?;
+        for choice in &self.choices {
+            votes_cast = votes_cast
+                .checked_add(choice.vote_count)
+                .ok_or(ErrorCode::CalculationError)?;
+        }
+
+        let threshold = self.voting_threshold.clone();
+        let approved = if self.is_multi_choice() {
+            self.winning_choice = self.leading_choice(&threshold);
+            self.winning_choice.is_some()
+        } else {
+            threshold.is_approved(self.weighted_yes, self.weighted_no)
+        };
+
+        let quorum_reached = threshold.quorum_reached(votes_cast, member_count);
+        let passed = quorum_reached && approved;
+        self.quorum_reached = quorum_reached;
+        self.settled_at = current_time;
+
+        // only a vote that reached quorum has a losing side
+        self.winning_vote = match (quorum_reached, self.winning_choice) {
+            (false, _) => None,
+            (true, Some(choice)) => Some(VoteType::Choice(choice)),
+            (true, None) if self.is_multi_choice() => None,
+            (true, None) if approved => Some(VoteType::Yes),
+            (true, None) => Some(VoteType::No),
+        };
+
+        if passed {
+            self.transition_to(ProposalStatus::Succeeded)
+        } else {
+            self.transition_to(ProposalStatus::Defeated)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn threshold(approval_percentage: u8) -> VotingThreshold {
+        VotingThreshold {
+            quorum_percentage: 50,
+            approval_percentage,
+            min_voting_period: 0,
+            max_voting_period: 0,
+            execution_delay: 0,
+        }
+    }
+
+    fn proposal(labels: &[&str]) -> ProposalState {
+        ProposalState {
+            proposal_seed: 1,
+            dao: Pubkey::default(),
+            proposal_owner: Pubkey::default(),
+            bump: 0,
+            proposal_title: String::new(),
+            proposal_details: String::new(),
+            proposal_cost: 0,
+            min_token_stake: 0,
+            vote_count_yes: 0,
+            vote_count_no: 0,
+            weighted_yes: 0,
+            weighted_no: 0,
+            voting_end_time: 0,
+            staking_vault_balance: 0,
+            recipient: Pubkey::default(),
+            status: ProposalStatus::Active,
+            eta: 0,
+            execution_delay: 0,
+            instruction_count: 0,
+            executed_instructions: 0,
+            vote_count_abstain: 0,
+            weighted_abstain: 0,
+            choices: labels
+                .iter()
+                .map(|label| ProposalChoice {
+                    label: label.to_string(),
+                    vote_count: 0,
+                    weighted: 0,
+                    staked: 0,
+                })
+                .collect(),
+            winning_choice: None,
+            total_reward_weight: 0,
+            reward_pool: 0,
+            finalized: false,
+            settled_at: 0,
+            staked_yes: 0,
+            staked_no: 0,
+            staked_abstain: 0,
+            winning_vote: None,
+            forfeit_bps: 0,
+            forfeited_stake: 0,
+            forfeit_to_winners: false,
+            snapshot_slot: 0,
+            snapshot_time: 0,
+            open_vote_records: 0,
+            deposit: 0,
+            quorum_reached: false,
+            metadata_uri: String::new(),
+            content_hash: [0; 32],
+            voting_start_time: 0,
+            proposal_index: 0,
+            voting_started: false,
+            voting_threshold: threshold(60),
+        }
+    }
+
+    #[test]
+    fn status_transitions_follow_the_lifecycle() {
+        use ProposalStatus::*;
+
+        for (from, to) in [
+            (Draft, Active),
+            (Draft, Cancelled),
+            (Active, Succeeded),
+            (Active, Defeated),
+            (Active, Cancelled),
+            (Succeeded, Queued),
+            (Succeeded, Executed),
+            (Succeeded, Expired),
+            (Succeeded, Cancelled),
+            (Queued, Executed),
+            (Queued, Cancelled),
+            (Queued, Expired),
+        ] {
+            assert!(from.can_transition_to(to));
+        }
+
+        for (from, to) in [
+            (Draft, Succeeded),
+            (Active, Queued),
+            (Active, Executed),
+            (Defeated, Succeeded),
+            (Defeated, Cancelled),
+            (Queued, Active),
+            (Executed, Active),
+            (Executed, Cancelled),
+            (Cancelled, Queued),
+            (Cancelled, Active),
+            (Expired, Executed),
+            (Active, Active),
+        ] {
+            assert!(!from.can_transition_to(to));
+        }
+
+        // a rejected transition leaves the status untouched
+        let mut p = proposal(&[]);
+        p.status = Executed;
+        assert!(p.transition_to(Active).is_err());
+        assert!(p.status == Executed);
+    }
+
+    #[test]
+    fn vote_types_must_match_the_proposal() {
+        let mut yes_no = proposal(&[]);
+        assert!(yes_no.add_votes(VoteType::Yes, 1, 10).is_ok());
+        assert!(yes_no.add_votes(VoteType::Abstain, 1, 10).is_ok());
+        assert!(yes_no.add_votes(VoteType::Choice(0), 1, 10).is_err());
+
+        let mut multi = proposal(&["a", "b"]);
+        assert!(multi.add_votes(VoteType::Choice(1), 1, 10).is_ok());
+        assert!(multi.add_votes(VoteType::Choice(2), 1, 10).is_err());
+        assert!(multi.add_votes(VoteType::No, 1, 10).is_err());
+    }
+
+    #[test]
+    fn withdrawn_votes_keep_voting_started() {
+        let mut p = proposal(&["a", "b"]);
+        assert!(!p.voting_started);
+
+        p.add_votes(VoteType::Choice(1), 1, 10).unwrap();
+        p.remove_votes(VoteType::Choice(1), 1, 10).unwrap();
+        assert!(p.voting_started);
+    }
+
+    #[test]
+    fn abstain_counts_toward_quorum_only() {
+        let mut p = proposal(&[]);
+        p.add_votes(VoteType::Yes, 1, 10).unwrap();
+        p.add_votes(VoteType::Abstain, 1, 1_000).unwrap();
+        p.voting_threshold = threshold(60);
+        p.settle(4, 0).unwrap();
+        assert!(p.status == ProposalStatus::Succeeded);
+
+        let mut p = proposal(&[]);
+        p.add_votes(VoteType::Yes, 1, 10).unwrap();
+        p.voting_threshold = threshold(60);
+        p.settle(4, 0).unwrap();
+        assert!(p.status == ProposalStatus::Defeated);
+    }
+
+    #[test]
+    fn voting_opens_after_the_discussion_period() {
+        let mut p = proposal(&[]);
+        p.status = ProposalStatus::Draft;
+        p.voting_start_time = 100;
+
+        assert!(p.open_voting(99, 5).is_err());
+        assert!(p.require_open_for_voting(99).is_err());
+
+        p.open_voting(100, 7).unwrap();
+        assert!(p.status == ProposalStatus::Active);
+        assert_eq!(p.snapshot_slot, 7);
+        assert_eq!(p.snapshot_time, 100);
+        assert!(p.open_voting(101, 8).is_err());
+    }
+
+    #[test]
+    fn deposit_is_slashed_only_without_quorum() {
+        // defeated with quorum keeps the deposit
+        let mut p = proposal(&[]);
+        p.add_votes(VoteType::No, 2, 10).unwrap();
+        p.voting_threshold = threshold(60);
+        p.settle(4, 0).unwrap();
+        assert!(p.status == ProposalStatus::Defeated);
+        assert!(!p.deposit_slashed());
+
+        let mut p = proposal(&[]);
+        p.add_votes(VoteType::Yes, 1, 10).unwrap();
+        p.voting_threshold = threshold(60);
+        p.settle(4, 0).unwrap();
+        assert!(p.deposit_slashed());
+
+        let mut p = proposal(&[]);
+        p.transition_to(ProposalStatus::Cancelled).unwrap();
+        assert!(!p.deposit_slashed());
+    }
+
+    #[test]
+    fn leading_choice_needs_approval_share_and_no_tie() {
+        let mut p = proposal(&["a", "b", "c"]);
+        p.add_votes(VoteType::Choice(0), 1, 20).unwrap();
+        p.add_votes(VoteType::Choice(1), 1, 70).unwrap();
+        p.add_votes(VoteType::Choice(2), 1, 10).unwrap();
+        assert_eq!(p.leading_choice(&threshold(60)), Some(1));
+        assert_eq!(p.leading_choice(&threshold(80)), None);
+
+        p.add_votes(VoteType::Choice(0), 1, 50).unwrap();
+        assert_eq!(p.leading_choice(&threshold(0)), None);
+
+        p.voting_threshold = threshold(0);
+        p.settle(4, 0).unwrap();
+        assert!(p.status == ProposalStatus::Defeated);
+        assert_eq!(p.winning_choice, None);
+    }
+
+    fn vote(vote_type: VoteType, tokens_staked: u64) -> VoteState {
+        VoteState {
+            voter: Pubkey::default(),
+            proposal_id: Pubkey::default(),
+            vote_seed: 0,
+            bump: 0,
+            vote_type,
+            tokens_staked,
+            vote_weight: tokens_staked,
+            claimed: false,
+            cast_by: Pubkey::default(),
+            delegated_weight: 0,
+            delegated_votes: 0,
+            reward_weight: tokens_staked,
+            rewards_claimed: 0,
+            stake_returned: false,
+        }
+    }
+
+    #[test]
+    fn conviction_forfeits_losing_stake() {
+        let mut p = proposal(&[]);
+        for (vote_type, stake) in [
+            (VoteType::Yes, 300),
+            (VoteType::No, 101),
+            (VoteType::Abstain, 50),
+        ] {
+            p.add_votes(vote_type, 1, stake).unwrap();
+            p.add_stake(vote_type, stake).unwrap();
+        }
+        p.voting_threshold = threshold(60);
+        p.settle(4, 0).unwrap();
+        assert!(p.winning_vote == Some(VoteType::Yes));
+        assert_eq!(p.losing_stake(), Some(101));
+
+        // what finalize records under a 50% conviction settlement
+        p.forfeit_bps = 5_000;
+        p.forfeited_stake = 50;
+        p.forfeit_to_winners = true;
+
+        // losers round up so their forfeits always cover forfeited_stake
+        assert_eq!(p.stake_forfeit(&vote(VoteType::No, 101)), Some(51));
+        assert_eq!(p.stake_forfeit(&vote(VoteType::Abstain, 50)), Some(0));
+        assert_eq!(p.stake_forfeit(&vote(VoteType::Yes, 300)), Some(0));
+        assert_eq!(p.forfeit_share(&vote(VoteType::Yes, 150)), Some(25));
+        assert_eq!(p.forfeit_share(&vote(VoteType::No, 101)), Some(0));
+    }
+
+    #[test]
+    fn no_losing_side_without_quorum() {
+        let mut p = proposal(&[]);
+        p.add_votes(VoteType::No, 1, 10).unwrap();
+        p.add_stake(VoteType::No, 10).unwrap();
+        p.voting_threshold = threshold(60);
+        p.settle(10, 0).unwrap();
+        assert!(p.winning_vote.is_none());
+
+        p.forfeit_bps = 5_000;
+        assert_eq!(p.stake_forfeit(&vote(VoteType::No, 10)), Some(0));
+    }
+
+    #[test]
+    fn only_finished_proposals_can_close() {
+        let mut p = proposal(&[]);
+        p.finalized = true;
+        p.status = ProposalStatus::Succeeded;
+        assert!(!p.can_close());
+
+        p.status = ProposalStatus::Defeated;
+        p.open_vote_records = 1;
+        assert!(!p.can_close());
+        p.open_vote_records = 0;
+        assert!(p.can_close());
+
+        p.status = ProposalStatus::Executed;
+        p.instruction_count = 2;
+        p.executed_instructions = 1;
+        assert!(!p.can_close());
+        p.executed_instructions = 2;
+        assert!(p.can_close());
+    }
 }
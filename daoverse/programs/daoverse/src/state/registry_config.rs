@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+// Entry of the daoverse dao registry at ["dao_index", index]
+// indexes run from 0 to DaoverseConfig::dao_count - 1
+#[account]
+#[derive(InitSpace)]
+pub struct DaoIndex {
+    pub index: u64,
+    pub dao: Pubkey,
+    pub bump: u8,
+}
+
+// Entry of a dao's proposal registry at ["proposal_index", dao, index]
+// indexes run from 0 to DaoConfig::total_proposals - 1
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalIndex {
+    pub dao: Pubkey,
+    pub index: u64,
+    pub proposal: Pubkey,
+    pub bump: u8,
+}
@@ -0,0 +1,195 @@
+use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
+
+pub const MAX_COUNCIL_MEMBERS: usize = 7;
+pub const MAX_GUARDIANS: usize = 3;
+pub const MAX_TREASURERS: usize = 5;
+
+// Council members can veto succeeded or queued proposals,
+// guardians can cancel any proposal before it is executed
+// and treasurers can spend the dao treasury within their limit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum DaoRole {
+    Council,
+    Guardian,
+    Treasurer,
+}
+
+// Treasurer and their spending allowance in dao mint base units
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Treasurer {
+    pub authority: Pubkey,
+    pub spending_limit: u64,
+    // seconds after which the allowance resets, 0 never resets
+    pub spending_period: u64,
+    pub period_start: i64,
+    pub spent_in_period: u64,
+}
+
+// Role holders of a dao, assigned by the creator until the dao
+// has its first proposal and by governance afterwards
+#[account]
+#[derive(InitSpace)]
+pub struct RoleRegistry {
+    pub dao: Pubkey,
+    pub bump: u8,
+    #[max_len(MAX_COUNCIL_MEMBERS)]
+    pub council: Vec<Pubkey>,
+    #[max_len(MAX_GUARDIANS)]
+    pub guardians: Vec<Pubkey>,
+    #[max_len(MAX_TREASURERS)]
+    pub treasurers: Vec<Treasurer>,
+}
+
+impl RoleRegistry {
+    pub fn has_role(&self, holder: &Pubkey, role: DaoRole) -> bool {
+        match role {
+            DaoRole::Council => self.council.contains(holder),
+            DaoRole::Guardian => self.guardians.contains(holder),
+            DaoRole::Treasurer => self.treasurers.iter().any(|t| t.authority == *holder),
+        }
+    }
+
+    // Add a role holder, granting the treasurer role again updates its limit
+    pub fn grant(
+        &mut self,
+        holder: Pubkey,
+        role: DaoRole,
+        spending_limit: u64,
+        spending_period: u64,
+    ) -> Result<()> {
+        let (holders, max) = match role {
+            DaoRole::Council => (&mut self.council, MAX_COUNCIL_MEMBERS),
+            DaoRole::Guardian => (&mut self.guardians, MAX_GUARDIANS),
+            DaoRole::Treasurer => {
+                if let Some(treasurer) = self.treasurers.iter_mut().find(|t| t.authority == holder)
+                {
+                    treasurer.spending_limit = spending_limit;
+                    treasurer.spending_period = spending_period;
+                    return Ok(());
+                }
+                require!(
+                    self.treasurers.len() < MAX_TREASURERS,
+                    ErrorCode::RoleLimitReached
+                );
+                self.treasurers.push(Treasurer {
+                    authority: holder,
+                    spending_limit,
+                    spending_period,
+                    period_start: 0,
+                    spent_in_period: 0,
+                });
+                return Ok(());
+            }
+        };
+
+        if !holders.contains(&holder) {
+            require!(holders.len() < max, ErrorCode::RoleLimitReached);
+            holders.push(holder);
+        }
+        Ok(())
+    }
+
+    pub fn revoke(&mut self, holder: &Pubkey, role: DaoRole) -> Result<()> {
+        let before = self.role_count(role);
+        match role {
+            DaoRole::Council => self.council.retain(|h| h != holder),
+            DaoRole::Guardian => self.guardians.retain(|h| h != holder),
+            DaoRole::Treasurer => self.treasurers.retain(|t| t.authority != *holder),
+        }
+        require!(self.role_count(role) < before, ErrorCode::RoleNotFound);
+        Ok(())
+    }
+
+    fn role_count(&self, role: DaoRole) -> usize {
+        match role {
+            DaoRole::Council => self.council.len(),
+            DaoRole::Guardian => self.guardians.len(),
+            DaoRole::Treasurer => self.treasurers.len(),
+        }
+    }
+
+    // Count a treasurer spend against their allowance for the current period
+    pub fn record_spend(&mut self, holder: &Pubkey, amount: u64, current_time: i64) -> Result<()> {
+        let treasurer = self
+            .treasurers
+            .iter_mut()
+            .find(|t| t.authority == *holder)
+            .ok_or(ErrorCode::Unauthorized)?;
+
+        let period = i64::try_from(treasurer.spending_period).unwrap_or(i64::MAX);
+        let period_ended = treasurer.period_start.saturating_add(period) <= current_time;
+        if treasurer.spending_period > 0 && period_ended {
+            treasurer.period_start = current_time;
+            treasurer.spent_in_period = 0;
+        }
+
+        let spent = treasurer
+            .spent_in_period
+            .checked_add(amount)
+            .ok_or(ErrorCode::CalculationError)?;
+        require!(
+            spent <= treasurer.spending_limit,
+            ErrorCode::SpendingLimitExceeded
+        );
+        treasurer.spent_in_period = spent;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> RoleRegistry {
+        RoleRegistry {
+            dao: Pubkey::default(),
+            bump: 0,
+            council: Vec::new(),
+            guardians: Vec::new(),
+            treasurers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn roles_are_granted_once_and_revoked() {
+        let mut r = registry();
+        let holder = Pubkey::new_unique();
+
+        r.grant(holder, DaoRole::Council, 0, 0).unwrap();
+        r.grant(holder, DaoRole::Council, 0, 0).unwrap();
+        assert_eq!(r.council.len(), 1);
+        assert!(r.has_role(&holder, DaoRole::Council));
+        assert!(!r.has_role(&holder, DaoRole::Guardian));
+
+        r.revoke(&holder, DaoRole::Council).unwrap();
+        assert!(!r.has_role(&holder, DaoRole::Council));
+        assert!(r.revoke(&holder, DaoRole::Council).is_err());
+
+        for _ in 0..MAX_GUARDIANS {
+            r.grant(Pubkey::new_unique(), DaoRole::Guardian, 0, 0)
+                .unwrap();
+        }
+        assert!(r
+            .grant(Pubkey::new_unique(), DaoRole::Guardian, 0, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn treasurer_allowance_resets_each_period() {
+        let mut r = registry();
+        let treasurer = Pubkey::new_unique();
+        r.grant(treasurer, DaoRole::Treasurer, 100, 10).unwrap();
+
+        r.record_spend(&treasurer, 60, 1_000).unwrap();
+        assert!(r.record_spend(&treasurer, 50, 1_005).is_err());
+        r.record_spend(&treasurer, 40, 1_009).unwrap();
+
+        // a new period starts once the previous one has passed
+        r.record_spend(&treasurer, 100, 1_010).unwrap();
+        assert!(r.record_spend(&treasurer, 1, 1_011).is_err());
+
+        assert!(r.record_spend(&Pubkey::new_unique(), 1, 1_011).is_err());
+    }
+}
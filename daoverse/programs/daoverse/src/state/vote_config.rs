@@ -5,6 +5,10 @@ use anchor_lang::prelude::*;
 pub enum VoteType {
     Yes,
     No,
+    // counts toward quorum but not approval
+    Abstain,
+    // index of an option on a multi-choice proposal
+    Choice(u8),
 }
 
 // Vote state account
@@ -17,5 +21,31 @@ pub struct VoteState {
     pub bump: u8,
     pub vote_type: VoteType,
     pub tokens_staked: u64,
+    // weight counted on the proposal tally under the dao voting model
+    pub vote_weight: u64,
     pub claimed: bool,
+    // the delegate who cast this vote, equal to voter for direct votes
+    pub cast_by: Pubkey,
+    // weight and voters added on top of vote_weight through delegations
+    pub delegated_weight: u64,
+    pub delegated_votes: u64,
+    // share of the proposal reward pool and how much of it was paid out
+    pub reward_weight: u64,
+    pub rewards_claimed: u64,
+    pub stake_returned: bool,
+}
+
+impl VoteState {
+    // voters and weight this record contributes to the tally, including delegations
+    pub fn tallied_votes(&self) -> Option<(u64, u64)> {
+        Some((
+            self.delegated_votes.checked_add(1)?,
+            self.vote_weight.checked_add(self.delegated_weight)?,
+        ))
+    }
+
+    // stake and rewards have been paid out, delegated records never hold any
+    pub fn is_settled(&self) -> bool {
+        self.claimed || (self.stake_returned && self.reward_weight == 0)
+    }
 }